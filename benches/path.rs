@@ -0,0 +1,77 @@
+use std::path::{Path, PathBuf};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use path_macro2::{path, path_str, PathStyle};
+
+fn static_segments(c: &mut Criterion) {
+    let mut group = c.benchmark_group("static");
+    group.bench_function("path!", |b| {
+        b.iter(|| path!(vendor / dll / windivert / x64 / windivert.sys))
+    });
+    group.bench_function("Path::join", |b| {
+        b.iter(|| {
+            Path::new(black_box("vendor"))
+                .join("dll")
+                .join("windivert")
+                .join("x64")
+                .join("windivert.sys")
+        })
+    });
+    group.bench_function("PathBuf::push", |b| {
+        b.iter(|| {
+            let mut path = PathBuf::from(black_box("vendor"));
+            path.push("dll");
+            path.push("windivert");
+            path.push("x64");
+            path.push("windivert.sys");
+            path
+        })
+    });
+    group.finish();
+}
+
+fn interpolated_segments(c: &mut Criterion) {
+    let root = PathBuf::from("/srv/index");
+    let shard = "shard-0042";
+    let id = 7;
+
+    let mut group = c.benchmark_group("interpolated");
+    group.bench_function("path!", |b| {
+        b.iter(|| path!({black_box(&root)} / data / {black_box(shard)} / doc_{black_box(id):06}.json))
+    });
+    group.bench_function("Path::join", |b| {
+        b.iter(|| {
+            black_box(&root)
+                .join("data")
+                .join(black_box(shard))
+                .join(format!("doc_{:06}.json", black_box(id)))
+        })
+    });
+    group.bench_function("path_str!", |b| {
+        b.iter(|| {
+            path_str!(PathStyle::Unix; {"/srv/index"} / data / {black_box(shard)} / doc_{black_box(id):06}.json)
+        })
+    });
+    group.finish();
+}
+
+fn spread_segments(c: &mut Criterion) {
+    let components: Vec<String> = ["a", "b", "c", "d", "e", "f"].iter().map(|s| s.to_string()).collect();
+
+    let mut group = c.benchmark_group("spread");
+    group.bench_function("path!", |b| {
+        b.iter(|| path!(root / {..black_box(&components)} / file.toml))
+    });
+    group.bench_function("Path::join", |b| {
+        b.iter(|| {
+            let path = black_box(&components)
+                .iter()
+                .fold(PathBuf::from("root"), |path, seg| path.join(seg));
+            path.join("file.toml")
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, static_segments, interpolated_segments, spread_segments);
+criterion_main!(benches);
@@ -0,0 +1,377 @@
+//! Procedural macros for [`path_macro2`](https://docs.rs/path_macro2).
+//!
+//! Use the macros through `path_macro2` (`checked_path!` needs its `checked`
+//! feature) rather than depending on this crate directly.
+
+use std::path::PathBuf;
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// What the checked path must point to.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Expect {
+    Any,
+    File,
+    Dir,
+}
+
+/// One path segment, as written in the macro call.
+struct Segment {
+    text: String,
+    span: Span,
+}
+
+/// [`path_const!`]-style path constant that must exist at compile time.
+///
+/// Resolves the segments relative to the `CARGO_MANIFEST_DIR` of the calling
+/// crate and expands to the absolute path as a `&'static str` literal, so it
+/// also works inside `concat!`. When a segment does not exist, compilation
+/// fails with an error pointing at that segment. Prefix the segments with
+/// `file:` or `dir:` to also require a file or a directory.
+///
+/// The syntax is the static subset of `path_const!`: identifiers, dotted,
+/// hyphenated and digit-leading names, string literals, `/` or `,`
+/// separators, and a leading `/` for a path that is already absolute.
+///
+/// Cargo is not told about the checked path, so removing it later only
+/// fails the build once the calling crate is recompiled for another reason.
+///
+/// Raw identifiers keep their `r#` prefix, as they do in `path_const!`.
+///
+/// Use it through the `path_macro2` re-export, which carries the examples.
+///
+/// [`path_const!`]: https://docs.rs/path_macro2/latest/path_macro2/macro.path_const.html
+#[proc_macro]
+pub fn checked_path(input: TokenStream) -> TokenStream {
+    match expand_checked_path(input) {
+        Ok(tokens) => tokens,
+        Err((message, span)) => compile_error(&message, span),
+    }
+}
+
+/// The text a lone literal segment contributes to a path: the value of a
+/// string or character literal, or a numeric or boolean literal exactly as
+/// written (`01`, `0x1F`, `-1`, `true`).
+///
+/// `concat!` would normalize numbers (`01` becomes `1`), and `macro_rules!`
+/// cannot tell the kinds of literal apart, so `path!` and `path_const!` hand
+/// lone literals to this macro instead.
+#[doc(hidden)]
+#[proc_macro]
+pub fn literal_segment(input: TokenStream) -> TokenStream {
+    match literal_segment_text(input) {
+        Ok((text, span)) => {
+            let mut literal = Literal::string(&text);
+            literal.set_span(span);
+            TokenTree::Literal(literal).into()
+        }
+        Err((message, span)) => compile_error(&message, span),
+    }
+}
+
+fn literal_segment_text(input: TokenStream) -> Result<(String, Span), (String, Span)> {
+    let tokens = flatten(&input.into_iter().collect::<Vec<_>>());
+
+    let unsupported = |span| {
+        Err((
+            "expected a string, character, numeric or boolean literal".to_string(),
+            span,
+        ))
+    };
+    match tokens.as_slice() {
+        [TokenTree::Ident(ident)] if matches!(ident.to_string().as_str(), "true" | "false") => {
+            Ok((ident.to_string(), ident.span()))
+        }
+        [TokenTree::Punct(minus), TokenTree::Literal(literal)] if minus.as_char() == '-' => {
+            let text = literal.to_string();
+            if text.starts_with(|c: char| c.is_ascii_digit()) {
+                Ok((format!("-{}", text), literal.span()))
+            } else {
+                unsupported(literal.span())
+            }
+        }
+        [TokenTree::Literal(literal)] => {
+            let text = literal.to_string();
+            let value = if text.starts_with('"') || text.starts_with('r') {
+                parse_str_literal(&text)
+            } else if text.starts_with('\'') {
+                parse_char_literal(&text)
+            } else if text.starts_with(|c: char| c.is_ascii_digit()) {
+                Some(text)
+            } else {
+                None
+            };
+            match value {
+                Some(value) => Ok((value, literal.span())),
+                None => unsupported(literal.span()),
+            }
+        }
+        [first, ..] => unsupported(first.span()),
+        [] => unsupported(Span::call_site()),
+    }
+}
+
+/// The separator to place after a `path_const!` segment:
+/// `segment_separator!(sep; segment tokens)`.
+///
+/// Expands to `""` when the segment is the leading root `/` or a literal whose
+/// value already ends in `/` or `\`, the same rule as the const-evaluated
+/// join, and to `sep` otherwise. Braced macro calls such as `{env!(..)}` are
+/// not expanded here, so they are always followed by `sep`.
+#[doc(hidden)]
+#[proc_macro]
+pub fn segment_separator(input: TokenStream) -> TokenStream {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let split = tokens
+        .iter()
+        .position(|token| matches!(token, TokenTree::Punct(p) if p.as_char() == ';'));
+    let (sep, segment) = match split {
+        Some(split) => (&tokens[..split], &tokens[split + 1..]),
+        None => return compile_error("expected `sep; segment`", Span::call_site()),
+    };
+
+    let ends_with_separator = match flatten(segment).as_slice() {
+        [TokenTree::Punct(p)] => p.as_char() == '/',
+        [TokenTree::Literal(literal)] => parse_str_literal(&literal.to_string())
+            .map_or(false, |value| value.ends_with(['/', '\\'])),
+        _ => false,
+    };
+    if ends_with_separator {
+        TokenTree::Literal(Literal::string("")).into()
+    } else {
+        sep.iter().cloned().collect()
+    }
+}
+
+/// `tokens` with invisible groups (from `$x:literal` and similar fragments)
+/// replaced by their contents.
+fn flatten(tokens: &[TokenTree]) -> Vec<TokenTree> {
+    let mut flat = Vec::new();
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+                let inner: Vec<TokenTree> = group.stream().into_iter().collect();
+                flat.extend(flatten(&inner));
+            }
+            _ => flat.push(token.clone()),
+        }
+    }
+    flat
+}
+
+fn expand_checked_path(input: TokenStream) -> Result<TokenStream, (String, Span)> {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let (expect, tokens) = parse_expect(&tokens);
+    let (rooted, segments) = parse_segments(tokens)?;
+
+    let mut path = if rooted {
+        PathBuf::from(std::path::MAIN_SEPARATOR.to_string())
+    } else {
+        let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR").ok_or_else(|| {
+            (
+                "`CARGO_MANIFEST_DIR` is not set; checked_path! must be compiled by cargo".to_string(),
+                Span::call_site(),
+            )
+        })?;
+        PathBuf::from(manifest_dir)
+    };
+
+    let mut written = String::new();
+    for segment in &segments {
+        if !written.is_empty() || rooted {
+            written.push('/');
+        }
+        written.push_str(&segment.text);
+        path.push(&segment.text);
+        if !path.exists() {
+            return Err((format!("`{}` does not exist", relative(&written, rooted)), segment.span));
+        }
+    }
+
+    let last_span = segments.last().map_or_else(Span::call_site, |segment| segment.span);
+    let written = relative(&written, rooted);
+    match expect {
+        Expect::File if !path.is_file() => {
+            return Err((format!("`{}` is not a file", written), last_span));
+        }
+        Expect::Dir if !path.is_dir() => {
+            return Err((format!("`{}` is not a directory", written), last_span));
+        }
+        _ => {}
+    }
+
+    let text = path
+        .to_str()
+        .ok_or_else(|| (format!("`{}` is not valid UTF-8", written), last_span))?;
+    Ok(TokenTree::Literal(Literal::string(text)).into())
+}
+
+/// The path as written, for error messages; relative ones name their anchor.
+fn relative(written: &str, rooted: bool) -> String {
+    if rooted {
+        written.to_string()
+    } else if written.is_empty() {
+        "CARGO_MANIFEST_DIR".to_string()
+    } else {
+        format!("CARGO_MANIFEST_DIR/{}", written)
+    }
+}
+
+/// Splits off a leading `file:` or `dir:`.
+fn parse_expect(tokens: &[TokenTree]) -> (Expect, &[TokenTree]) {
+    if let [TokenTree::Ident(ident), TokenTree::Punct(colon), rest @ ..] = tokens {
+        if colon.as_char() == ':' && colon.spacing() == Spacing::Alone {
+            match ident.to_string().as_str() {
+                "file" => return (Expect::File, rest),
+                "dir" => return (Expect::Dir, rest),
+                _ => {}
+            }
+        }
+    }
+    (Expect::Any, tokens)
+}
+
+/// Splits tokens at `/` and `,`, returning whether a leading `/` was present.
+fn parse_segments(tokens: &[TokenTree]) -> Result<(bool, Vec<Segment>), (String, Span)> {
+    let rooted = matches!(tokens.first(), Some(TokenTree::Punct(p)) if p.as_char() == '/');
+    let tokens = if rooted { &tokens[1..] } else { tokens };
+
+    let mut segments = Vec::new();
+    let mut current: Option<Segment> = None;
+    for token in tokens {
+        match token {
+            TokenTree::Punct(p) if p.as_char() == '/' || p.as_char() == ',' => {
+                segments.extend(current.take());
+            }
+            _ => {
+                let text = token_text(token)?;
+                match &mut current {
+                    Some(segment) => segment.text.push_str(&text),
+                    None => {
+                        current = Some(Segment {
+                            text,
+                            span: token.span(),
+                        })
+                    }
+                }
+            }
+        }
+    }
+    segments.extend(current);
+    Ok((rooted, segments))
+}
+
+/// The characters a token contributes to its segment.
+fn token_text(token: &TokenTree) -> Result<String, (String, Span)> {
+    match token {
+        TokenTree::Ident(ident) => Ok(ident.to_string()),
+        TokenTree::Punct(punct) => Ok(punct.as_char().to_string()),
+        TokenTree::Literal(literal) => {
+            let text = literal.to_string();
+            if text.starts_with('"') || text.starts_with('r') {
+                parse_str_literal(&text)
+                    .ok_or_else(|| ("unsupported string literal".to_string(), literal.span()))
+            } else if text.starts_with(|c: char| c.is_ascii_digit()) {
+                Ok(text)
+            } else {
+                Err(("expected a string or numeric literal".to_string(), literal.span()))
+            }
+        }
+        TokenTree::Group(group) => Err((
+            "checked_path! only accepts static segments; interpolation is not supported".to_string(),
+            group.span(),
+        )),
+    }
+}
+
+/// The value of a (raw) string literal.
+fn parse_str_literal(text: &str) -> Option<String> {
+    if let Some(raw) = text.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let body = &raw[hashes..];
+        return body
+            .strip_prefix('"')?
+            .strip_suffix(&"#".repeat(hashes))?
+            .strip_suffix('"')
+            .map(str::to_string);
+    }
+
+    unescape(text.strip_prefix('"')?.strip_suffix('"')?)
+}
+
+/// The value of a character literal.
+fn parse_char_literal(text: &str) -> Option<String> {
+    let value = unescape(text.strip_prefix('\'')?.strip_suffix('\'')?)?;
+    if value.chars().count() == 1 {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Resolves the escapes in the body of a string or character literal.
+fn unescape(body: &str) -> Option<String> {
+    let body: Vec<char> = body.chars().collect();
+    let mut value = String::with_capacity(body.len());
+    let mut i = 0;
+    while i < body.len() {
+        let c = body[i];
+        i += 1;
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        let escape = *body.get(i)?;
+        i += 1;
+        match escape {
+            'n' => value.push('\n'),
+            'r' => value.push('\r'),
+            't' => value.push('\t'),
+            '0' => value.push('\0'),
+            '\\' | '\'' | '"' => value.push(escape),
+            'x' => {
+                let hex: String = body.get(i..i + 2)?.iter().collect();
+                i += 2;
+                value.push(char::from(u8::from_str_radix(&hex, 16).ok()?));
+            }
+            'u' => {
+                let close = i + body[i..].iter().position(|&c| c == '}')?;
+                let hex: String = body.get(i + 1..close)?.iter().collect();
+                i = close + 1;
+                value.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+            }
+            '\n' => {
+                // Line continuation: skip the newline and the indentation after it
+                while body.get(i).map_or(false, |c| c.is_whitespace()) {
+                    i += 1;
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some(value)
+}
+
+/// `::core::compile_error! { message }` reported at `span`.
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let tokens = vec![
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("core", span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Brace,
+            TokenTree::Literal(Literal::string(message)).into(),
+        )),
+    ];
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
@@ -0,0 +1,111 @@
+use std::path::{Path, PathBuf};
+
+use crate::{ErrorKind, PathMacroError};
+
+/// A base directory that a path can start from: `~`, `@config`, `@cache`,
+/// `@data`, `@temp`, `@cwd` or `@exe`.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anchor {
+    Home,
+    Config,
+    Cache,
+    Data,
+    Temp,
+    Cwd,
+    Exe,
+}
+
+impl Anchor {
+    /// The anchor as written in a macro call.
+    fn name(self) -> &'static str {
+        match self {
+            Anchor::Home => "~",
+            Anchor::Config => "@config",
+            Anchor::Cache => "@cache",
+            Anchor::Data => "@data",
+            Anchor::Temp => "@temp",
+            Anchor::Cwd => "@cwd",
+            Anchor::Exe => "@exe",
+        }
+    }
+
+    /// Looks up the directory, or `None` when it cannot be determined.
+    ///
+    /// Per-user directories follow the platform conventions: `%APPDATA%` and
+    /// `%LOCALAPPDATA%` on Windows, `~/Library` on macOS, and the XDG base
+    /// directories (`$XDG_CONFIG_HOME`, `~/.config`, ...) elsewhere.
+    fn resolve(self) -> Option<PathBuf> {
+        match self {
+            Anchor::Home => home_dir(),
+            Anchor::Config => {
+                platform_dir("APPDATA", "Library/Application Support", "XDG_CONFIG_HOME", ".config")
+            }
+            Anchor::Cache => platform_dir("LOCALAPPDATA", "Library/Caches", "XDG_CACHE_HOME", ".cache"),
+            Anchor::Data => platform_dir(
+                "APPDATA",
+                "Library/Application Support",
+                "XDG_DATA_HOME",
+                ".local/share",
+            ),
+            Anchor::Temp => Some(std::env::temp_dir()),
+            Anchor::Cwd => std::env::current_dir().ok(),
+            Anchor::Exe => std::env::current_exe()
+                .ok()
+                .and_then(|exe| exe.parent().map(Path::to_path_buf)),
+        }
+    }
+}
+
+/// The current user's home directory, from `USERPROFILE` on Windows and
+/// `HOME` elsewhere.
+fn home_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        env_dir("USERPROFILE")
+    } else {
+        env_dir("HOME")
+    }
+}
+
+/// An absolute directory from the environment variable `var`.
+fn env_dir(var: &str) -> Option<PathBuf> {
+    std::env::var_os(var)
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+}
+
+/// A per-user directory: `windows_var` on Windows, `macos_dir` under the home
+/// directory on macOS, and `xdg_var` or `xdg_default` under the home
+/// directory elsewhere.
+fn platform_dir(
+    windows_var: &str,
+    macos_dir: &str,
+    xdg_var: &str,
+    xdg_default: &str,
+) -> Option<PathBuf> {
+    if cfg!(windows) {
+        env_dir(windows_var)
+    } else if cfg!(target_os = "macos") {
+        home_dir().map(|home| home.join(macos_dir))
+    } else {
+        env_dir(xdg_var).or_else(|| home_dir().map(|home| home.join(xdg_default)))
+    }
+}
+
+/// [`Anchor::resolve`] for `path!`, which has no way to report an error.
+#[doc(hidden)]
+pub fn expect_anchor(anchor: Anchor) -> PathBuf {
+    anchor
+        .resolve()
+        .unwrap_or_else(|| panic!("path!: {}", unavailable(anchor)))
+}
+
+/// [`Anchor::resolve`] for `try_path!`, reported as segment #0.
+#[doc(hidden)]
+pub fn try_anchor(anchor: Anchor) -> Result<PathBuf, PathMacroError> {
+    anchor.resolve().ok_or_else(|| unavailable(anchor))
+}
+
+fn unavailable(anchor: Anchor) -> PathMacroError {
+    PathMacroError::new(0, anchor.name().into(), ErrorKind::BaseUnavailable)
+}
@@ -0,0 +1,25 @@
+use std::path::Path;
+
+/// Prints a `cargo:rerun-if-changed` directive for every path, for use in
+/// build scripts with paths built at runtime.
+///
+/// Each path is printed with the separators of the platform the build script
+/// runs on. See [`rerun_if_changed!`](crate::rerun_if_changed!) for paths
+/// known at compile time.
+///
+/// ```rust
+/// use path_macro2::{path, rerun_if_changed};
+///
+/// let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+/// let sources = [path!(vendor / src / common.c), path!(vendor / src / {target_os}.c)];
+/// rerun_if_changed(&sources);
+/// ```
+pub fn rerun_if_changed<I>(paths: I)
+where
+    I: IntoIterator,
+    I::Item: AsRef<Path>,
+{
+    for path in paths {
+        println!("cargo:rerun-if-changed={}", path.as_ref().display());
+    }
+}
@@ -0,0 +1,77 @@
+use std::path::{is_separator, Component, Path, PathBuf};
+
+use crate::{ErrorKind, PathMacroError};
+
+/// Longest segment, in bytes, accepted by [`try_path!`](crate::try_path).
+///
+/// Matches the file name limit of common file systems (ext4, NTFS, APFS).
+pub const MAX_SEGMENT_LEN: usize = 255;
+
+/// Device names that Windows reserves in every directory, with or without an extension.
+const RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+fn is_reserved(name: &str) -> bool {
+    let stem = name.split('.').next().unwrap_or(name).trim_end_matches(' ');
+    RESERVED_NAMES
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(stem))
+}
+
+/// Checks that `segment` is a single, relative, portable path component.
+fn check_segment(segment: &Path) -> Result<(), ErrorKind> {
+    if segment.as_os_str().is_empty() {
+        return Err(ErrorKind::Empty);
+    }
+    let text = segment.as_os_str().to_string_lossy();
+    if text.contains('\0') {
+        return Err(ErrorKind::Nul);
+    }
+    if segment.as_os_str().len() > MAX_SEGMENT_LEN {
+        return Err(ErrorKind::TooLong);
+    }
+    for component in segment.components() {
+        match component {
+            Component::Prefix(_) => return Err(ErrorKind::Prefix),
+            Component::RootDir => return Err(ErrorKind::Absolute),
+            Component::ParentDir => return Err(ErrorKind::ParentDir),
+            Component::CurDir => return Err(ErrorKind::CurDir),
+            Component::Normal(_) => {}
+        }
+    }
+    // Windows reads these as a prefix, a root or a separator, so they are
+    // rejected everywhere for the same reason as reserved names
+    if text.starts_with(r"\\")
+        || matches!(text.as_bytes(), [drive, b':', ..] if drive.is_ascii_alphabetic())
+    {
+        return Err(ErrorKind::Prefix);
+    }
+    if text.starts_with('\\') {
+        return Err(ErrorKind::Absolute);
+    }
+    if text.chars().any(|c| c == '\\' || is_separator(c)) {
+        return Err(ErrorKind::Separator);
+    }
+    if text.ends_with(['.', ' ']) {
+        return Err(ErrorKind::Trailing);
+    }
+    if is_reserved(&text) {
+        return Err(ErrorKind::Reserved);
+    }
+    Ok(())
+}
+
+/// Pushes the interpolated segment at `index` after validating it.
+#[doc(hidden)]
+pub fn push_checked(
+    path: &mut PathBuf,
+    index: usize,
+    segment: &Path,
+) -> Result<(), PathMacroError> {
+    check_segment(segment)
+        .map_err(|kind| PathMacroError::new(index, segment.as_os_str().to_owned(), kind))?;
+    path.push(segment);
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use std::cmp::Ordering;
+use std::ffi::OsStr;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// A `&'static str` path that can be declared in `const` and `static` items
+/// and derefs to [`Path`].
+///
+/// [`Path::new`] is not a `const fn`, so a `&'static Path` cannot be written in
+/// a `static` item directly. `ConstPath` stores the string instead and
+/// converts on access. Built by [`static_path!`](crate::static_path) or
+/// [`ConstPath::new`].
+///
+/// ```rust
+/// use path_macro2::{static_path, ConstPath};
+/// use std::path::Path;
+///
+/// static TOOLS: &[ConstPath] = &[
+///     static_path!(vendor / bin / cl.exe),
+///     static_path!(vendor / bin / link.exe),
+/// ];
+///
+/// let linker: &'static Path = TOOLS[1].as_path();
+/// assert_eq!(linker.file_name().unwrap(), "link.exe");
+/// assert!(TOOLS[0].starts_with("vendor"));
+/// ```
+///
+/// Comparison and hashing go through [`as_path`](ConstPath::as_path), so
+/// `ConstPath::new("a//b")` equals `ConstPath::new("a/b")`, just like the
+/// [`Path`]s they deref to.
+#[derive(Clone, Copy)]
+pub struct ConstPath(&'static str);
+
+impl ConstPath {
+    /// Wraps a static path string, typically from [`path_const!`](crate::path_const).
+    pub const fn new(path: &'static str) -> Self {
+        ConstPath(path)
+    }
+
+    /// The path as a `&'static Path`.
+    pub fn as_path(self) -> &'static Path {
+        Path::new(self.0)
+    }
+
+    /// The path as the string it was built from.
+    pub const fn as_str(self) -> &'static str {
+        self.0
+    }
+}
+
+impl Deref for ConstPath {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        self.as_path()
+    }
+}
+
+impl AsRef<Path> for ConstPath {
+    fn as_ref(&self) -> &Path {
+        self.as_path()
+    }
+}
+
+impl AsRef<OsStr> for ConstPath {
+    fn as_ref(&self) -> &OsStr {
+        OsStr::new(self.0)
+    }
+}
+
+impl AsRef<str> for ConstPath {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl PartialEq for ConstPath {
+    fn eq(&self, other: &ConstPath) -> bool {
+        self.as_path() == other.as_path()
+    }
+}
+
+impl Eq for ConstPath {}
+
+impl Hash for ConstPath {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_path().hash(state)
+    }
+}
+
+impl PartialOrd for ConstPath {
+    fn partial_cmp(&self, other: &ConstPath) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ConstPath {
+    fn cmp(&self, other: &ConstPath) -> Ordering {
+        self.as_path().cmp(other.as_path())
+    }
+}
+
+impl PartialEq<Path> for ConstPath {
+    fn eq(&self, other: &Path) -> bool {
+        self.as_path() == other
+    }
+}
+
+impl PartialEq<ConstPath> for Path {
+    fn eq(&self, other: &ConstPath) -> bool {
+        self == other.as_path()
+    }
+}
+
+impl PartialEq<PathBuf> for ConstPath {
+    fn eq(&self, other: &PathBuf) -> bool {
+        self.as_path() == other
+    }
+}
+
+impl PartialEq<ConstPath> for PathBuf {
+    fn eq(&self, other: &ConstPath) -> bool {
+        self == other.as_path()
+    }
+}
+
+impl From<ConstPath> for &'static Path {
+    fn from(path: ConstPath) -> Self {
+        path.as_path()
+    }
+}
+
+impl From<ConstPath> for PathBuf {
+    fn from(path: ConstPath) -> Self {
+        PathBuf::from(path.0)
+    }
+}
+
+impl fmt::Debug for ConstPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_path(), f)
+    }
+}
+
+impl fmt::Display for ConstPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
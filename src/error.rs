@@ -0,0 +1,99 @@
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+
+/// Error returned by [`try_path!`](crate::try_path) when an interpolated
+/// segment is empty, could escape the path being built, or is not a portable
+/// file name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathMacroError {
+    index: usize,
+    segment: OsString,
+    kind: ErrorKind,
+}
+
+/// The reason a segment was rejected by [`try_path!`](crate::try_path).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The segment is empty.
+    Empty,
+    /// The segment is absolute or starts with a root (`/etc/passwd`, `\Windows`).
+    Absolute,
+    /// The segment carries a Windows prefix (`C:`, `\\server\share`).
+    Prefix,
+    /// The segment is or contains a parent directory reference (`..`).
+    ParentDir,
+    /// The segment is or starts with a current directory reference (`.`).
+    CurDir,
+    /// The segment ends in a dot or a space, which Windows silently strips (`x.`).
+    Trailing,
+    /// The segment contains a path separator (`a/b`, `a\b`).
+    Separator,
+    /// The segment contains a NUL byte.
+    Nul,
+    /// The segment is a reserved device name on Windows (`CON`, `nul.txt`, `COM1`).
+    Reserved,
+    /// The segment is longer than [`MAX_SEGMENT_LEN`](crate::MAX_SEGMENT_LEN) bytes.
+    TooLong,
+    /// The base directory of a leading anchor (`~`, `@config`, ...) could not be
+    /// determined.
+    BaseUnavailable,
+}
+
+impl PathMacroError {
+    pub(crate) fn new(index: usize, segment: OsString, kind: ErrorKind) -> Self {
+        Self {
+            index,
+            segment,
+            kind,
+        }
+    }
+
+    /// Position of the rejected segment in the macro call, starting at 0.
+    ///
+    /// A leading `/` is not counted, and every item of a `{..expr}` spread
+    /// shares the index of the spread.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The rejected segment value.
+    pub fn segment(&self) -> &OsStr {
+        &self.segment
+    }
+
+    /// Why the segment was rejected.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ErrorKind::Empty => "segment is empty",
+            ErrorKind::Absolute => "segment is an absolute path",
+            ErrorKind::Prefix => "segment has a drive or UNC prefix",
+            ErrorKind::ParentDir => "segment refers to a parent directory",
+            ErrorKind::CurDir => "segment refers to the current directory",
+            ErrorKind::Trailing => "segment ends in a dot or a space",
+            ErrorKind::Separator => "segment contains a path separator",
+            ErrorKind::Nul => "segment contains a NUL byte",
+            ErrorKind::Reserved => "segment is a reserved name on Windows",
+            ErrorKind::TooLong => "segment is too long",
+            ErrorKind::BaseUnavailable => "base directory could not be determined",
+        })
+    }
+}
+
+impl fmt::Display for PathMacroError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid path segment #{} {:?}: {}",
+            self.index, self.segment, self.kind
+        )
+    }
+}
+
+impl std::error::Error for PathMacroError {}
@@ -0,0 +1,22 @@
+use std::ffi::{CString, NulError};
+use std::path::PathBuf;
+
+/// Converts a built path into a C string, keeping the raw bytes on Unix.
+///
+/// Other platforms have no byte representation of `OsString`, so the path is
+/// converted to UTF-8 and unpaired surrogates are replaced with `U+FFFD`.
+#[doc(hidden)]
+// `std::ffi::NulError` is stable since 1.0; clippy sees the 1.64 `alloc` re-export
+#[allow(clippy::incompatible_msrv)]
+pub fn into_cstring(path: PathBuf) -> Result<CString, NulError> {
+    #[cfg(unix)]
+    let bytes = {
+        use std::os::unix::ffi::OsStringExt;
+        path.into_os_string().into_vec()
+    };
+
+    #[cfg(not(unix))]
+    let bytes = path.to_string_lossy().into_owned().into_bytes();
+
+    CString::new(bytes)
+}
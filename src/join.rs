@@ -0,0 +1,66 @@
+//! Const-evaluated joining for `path_const!` segments that name `const` items.
+
+/// Whether no separator is needed after `segment`: it is a root, drive root or
+/// UNC prefix, or otherwise already ends with one.
+const fn ends_with_separator(segment: &str) -> bool {
+    matches!(segment.as_bytes(), [.., b'/' | b'\\'])
+}
+
+/// Length of `segments` joined with `sep`.
+#[doc(hidden)]
+pub const fn joined_len(sep: &str, segments: &[&str]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < segments.len() {
+        len += segments[i].len();
+        if i + 1 < segments.len() && !ends_with_separator(segments[i]) {
+            len += sep.len();
+        }
+        i += 1;
+    }
+    len
+}
+
+/// Joins `segments` with `sep` into a byte array of `N == joined_len(sep, segments)`.
+#[doc(hidden)]
+pub const fn join_segments<const N: usize>(sep: &str, segments: &[&str]) -> [u8; N] {
+    let mut joined = [0u8; N];
+    let mut at = 0;
+    let mut i = 0;
+    while i < segments.len() {
+        let segment = segments[i].as_bytes();
+        let mut j = 0;
+        while j < segment.len() {
+            joined[at] = segment[j];
+            at += 1;
+            j += 1;
+        }
+        if i + 1 < segments.len() && !ends_with_separator(segments[i]) {
+            let sep = sep.as_bytes();
+            let mut j = 0;
+            while j < sep.len() {
+                joined[at] = sep[j];
+                at += 1;
+                j += 1;
+            }
+        }
+        i += 1;
+    }
+    joined
+}
+
+/// Whether `bytes` ends in its only NUL byte, as a C string must.
+#[doc(hidden)]
+pub const fn is_c_str(bytes: &[u8]) -> bool {
+    if bytes.is_empty() {
+        return false;
+    }
+    let mut i = 0;
+    while i + 1 < bytes.len() {
+        if bytes[i] == 0 {
+            return false;
+        }
+        i += 1;
+    }
+    bytes[i] == 0
+}
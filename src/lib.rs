@@ -1,4 +1,72 @@
-#![doc = include_str!("../README.md")]
+// The README examples use `path!`, which needs `std`
+#![cfg_attr(feature = "std", doc = include_str!("../README.md"))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod anchor;
+#[cfg(feature = "std")]
+mod build;
+#[cfg(feature = "std")]
+mod checked;
+#[cfg(feature = "std")]
+mod const_path;
+#[cfg(feature = "std")]
+mod error;
+#[cfg(feature = "std")]
+mod ffi;
+mod join;
+mod style;
+mod wide;
+
+#[cfg(feature = "std")]
+pub use build::rerun_if_changed;
+#[cfg(feature = "std")]
+pub use checked::MAX_SEGMENT_LEN;
+#[cfg(feature = "std")]
+pub use const_path::ConstPath;
+#[cfg(feature = "std")]
+pub use error::{ErrorKind, PathMacroError};
+pub use style::PathStyle;
+
+/// # Examples
+///
+/// ```rust
+/// use path_macro2::checked_path;
+///
+/// const MANIFEST: &str = checked_path!(file: Cargo.toml);
+/// const SOURCES: &str = checked_path!(dir: src);
+///
+/// let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+/// assert_eq!(std::path::Path::new(MANIFEST), root.join("Cargo.toml"));
+/// assert_eq!(std::path::Path::new(SOURCES), root.join("src"));
+/// ```
+#[cfg(feature = "checked")]
+pub use path_macro2_macros::checked_path;
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::format;
+    #[cfg(feature = "alloc")]
+    pub use alloc::string::{String, ToString};
+
+    pub use crate::join::{is_c_str, join_segments, joined_len};
+    pub use path_macro2_macros::{literal_segment, segment_separator};
+    pub use crate::wide::{encode_wide_path, utf16_len};
+
+    #[cfg(feature = "std")]
+    pub use crate::anchor::{expect_anchor, try_anchor, Anchor};
+    #[cfg(feature = "std")]
+    pub use crate::checked::push_checked;
+    #[cfg(feature = "std")]
+    pub use crate::ffi::into_cstring;
+
+    #[cfg(feature = "camino")]
+    pub use camino;
+}
 
 /// Cross-platform path construction macro.
 ///
@@ -10,12 +78,79 @@
 /// - `path!(a / b / c)` — uses slashes (`/`)
 /// - `path!(a, b, c)` — uses commas (`,`).
 ///
+/// A leading `/` (in either style) anchors the path at the filesystem root:
+/// `path!(/ usr / local)` and `path!(/ usr, local)` both build `/usr/local` on
+/// Unix and `\usr\local` on Windows.
+///
+/// A leading `~` anchors the path at the current user's home directory, read
+/// at runtime from `HOME` (`USERPROFILE` on Windows): `path!(~ / .config / app)`.
+/// It must be followed by a separator or nothing; `~user` does not compile.
+/// Other base directories are anchored with a leading `@name`:
+///
+/// - `@config` — `$XDG_CONFIG_HOME` or `~/.config`; `~/Library/Application Support`
+///   on macOS; `%APPDATA%` on Windows
+/// - `@cache` — `$XDG_CACHE_HOME` or `~/.cache`; `~/Library/Caches` on macOS;
+///   `%LOCALAPPDATA%` on Windows
+/// - `@data` — `$XDG_DATA_HOME` or `~/.local/share`; `~/Library/Application Support`
+///   on macOS; `%APPDATA%` on Windows
+/// - `@temp` — [`std::env::temp_dir`]
+/// - `@cwd` — [`std::env::current_dir`]
+/// - `@exe` — the directory containing [`std::env::current_exe`]
+///
+/// Any other leading `@name` fails to compile, so a typo does not silently
+/// build a relative path; quote it (`"@name"`) for a literal segment.
+/// `path!` panics when the base directory cannot be determined; [`try_path!`]
+/// returns an error instead.
+///
 /// # Supported Segment Types
 ///
 /// - **Identifiers:** `vendor`, `dll` (converted with `stringify!`)
 /// - **Dotted identifiers:** `file.txt`, `windivert.c` (treated as single segments)
+/// - **Hyphenated and digit-leading names:** `my-app`, `2024-01-01`, `v1.2.3`
+///   (kept exactly as written)
 /// - **String literals:** `"my folder"`, `"file name.txt"`
-/// - **Variable expressions:** wrapped in curly braces `{base_path}`, `{my_var}`
+/// - **Variable expressions:** wrapped in curly braces `{base_path}`, `{my_var}`;
+///   accepts anything implementing `AsRef<Path>` (`&str`, `String`, `PathBuf`,
+///   `&Path`, `OsString`, ...) without a lossy round-trip through `String`
+/// - **Display expressions:** wrapped in curly braces with a `%` prefix,
+///   `{%version}`, `{%id + 1}`; formatted with [`ToString`]
+/// - **Optional expressions:** wrapped in curly braces with a `?` prefix,
+///   `{?profile}`; accepts `Option<impl AsRef<Path>>` and skips the segment
+///   when it is `None`
+/// - **Spread expressions:** wrapped in curly braces with a `..` prefix,
+///   `{..components}`; accepts any `IntoIterator` whose items implement
+///   `AsRef<Path>` and pushes one segment per item (use `{..&components}` to
+///   borrow a collection)
+/// - **Interpolated segments:** `{expr}` groups written next to other tokens,
+///   `app_{date}.log`, `run-{id:04}`; formatted in place with [`format!`],
+///   including format specs after a `:` (also on their own: `{id:04}`)
+///
+/// # Output Types
+///
+/// A leading `as Type:` selects what is built instead of a `PathBuf`:
+///
+/// - `as String` — built directly with the native [`PathStyle`], like
+///   [`path_str!`]; interpolated values must be `AsRef<str>`, and a `~` or
+///   `@name` base directory must be valid UTF-8
+/// - `as OsString` — the `PathBuf`'s buffer, moved out without copying
+/// - `as Box<Path>` — the `PathBuf`'s buffer, shrunk to fit
+/// - `as Rc<Path>`, `as Arc<Path>` — copied once into the shared allocation
+/// - `as Utf8PathBuf` — built directly by [`utf8_path!`] (requires the
+///   `camino` feature)
+/// - `as PathBuf` — the default, spelled out
+///
+/// The types are matched as written; any other selector, including a
+/// qualified one such as `as std::string::String:`, fails to compile.
+///
+/// ```rust
+/// use path_macro2::path;
+/// use std::path::Path;
+/// use std::sync::Arc;
+///
+/// let name = "app.log";
+/// let log: String = path!(as String: logs / {name});
+/// let cached: Arc<Path> = path!(as Arc<Path>: cache / {name});
+/// ```
 ///
 /// # Examples
 ///
@@ -33,6 +168,29 @@
 /// let base = "vendor";
 /// let path4 = path!({base} / dll / file.txt);
 ///
+/// // `PathBuf`, `&Path` and `OsString` values are pushed as-is
+/// let root = std::env::temp_dir();
+/// let path5 = path!({root} / cache / index.db);
+///
+/// // `Display` values need the explicit `%` form
+/// let version = 2;
+/// let path6 = path!(libs / {%version} / library.so);
+///
+/// // Optional segments are skipped when `None`
+/// let profile: Option<&str> = None;
+/// let path9 = path!(config / {?profile} / app.toml);
+/// assert_eq!(path9, path!(config / app.toml));
+///
+/// // Spread any iterator of segments in place
+/// let components = vec!["nested", "dirs"];
+/// let path10 = path!(root / {..&components} / file.toml);
+/// assert_eq!(path10, path!(root / nested / dirs / file.toml));
+///
+/// // Interpolation inside a segment, with optional format specs
+/// let date = "2026-10-16";
+/// let path7 = path!(logs / app-{date}.log);
+/// let path8 = path!(runs / run_{version:04} / out.csv);
+///
 /// // ---
 /// // Platform-specific examples
 ///
@@ -58,75 +216,434 @@
 /// ```
 ///
 /// Works consistently across all platforms.
+#[cfg(feature = "std")]
 #[macro_export]
 macro_rules! path {
-    // === Phase 1: Build segments (accumulate tokens until a delimiter is found) ===
+    // === Push one parsed segment (see `__path_segments!`), skipping empty ones ===
+    (@push $path:ident [root]) => {
+        $path.push(std::path::Component::RootDir)
+    };
 
-    // When encountering a string literal, treat it as a complete segment
-    (@build_seg [$($result:expr),*] [$($current:tt)*] $lit:literal $($rest:tt)*) => {
-        path!(@build_seg [$($result,)* path!(@finish_seg [$($current)*]), $lit.to_string()] [] $($rest)*)
+    (@push $path:ident [anchor $name:ident]) => {
+        $path.push($crate::__private::expect_anchor($crate::__private::Anchor::$name))
     };
 
-    // When encountering a variable expression {expr}, treat it as a complete segment
-    (@build_seg [$($result:expr),*] [$($current:tt)*] { $($expr:tt)+ } $($rest:tt)*) => {
-        path!(@build_seg [$($result,)* path!(@finish_seg [$($current)*]), ($($expr)+).to_string()] [] $($rest)*)
+    (@push $path:ident [str $($seg:tt)+]) => {{
+        let seg: &str = $($seg)+;
+        if !seg.is_empty() {
+            $path.push(seg);
+        }
+    }};
+
+    (@push $path:ident [string $($seg:tt)+]) => {{
+        let seg: $crate::__private::String = $($seg)+;
+        if !seg.is_empty() {
+            $path.push(seg);
+        }
+    }};
+
+    (@push $path:ident [path $($expr:tt)+]) => {
+        match AsRef::<std::path::Path>::as_ref(&($($expr)+)) {
+            seg => if !seg.as_os_str().is_empty() {
+                $path.push(seg);
+            },
+        }
     };
 
-    // When encountering a slash `/`, complete the current segment
-    (@build_seg [$($result:expr),*] [$($current:tt)+] / $($rest:tt)*) => {
-        path!(@build_seg [$($result,)* path!(@finish_seg [$($current)+])] [] $($rest)*)
+    (@push $path:ident [opt $($expr:tt)+]) => {
+        if let Some(seg) = &($($expr)+) {
+            let seg = AsRef::<std::path::Path>::as_ref(seg);
+            if !seg.as_os_str().is_empty() {
+                $path.push(seg);
+            }
+        }
     };
 
-    // When encountering a slash `/` but the current segment is empty, skip it
-    (@build_seg [$($result:expr),*] [] / $($rest:tt)*) => {
-        path!(@build_seg [$($result),*] [] $($rest)*)
+    (@push $path:ident [spread $($expr:tt)+]) => {
+        for seg in $($expr)+ {
+            let seg = AsRef::<std::path::Path>::as_ref(&seg);
+            if !seg.as_os_str().is_empty() {
+                $path.push(seg);
+            }
+        }
     };
 
-    // When encountering a comma `,`, complete the current segment
-    (@build_seg [$($result:expr),*] [$($current:tt)+] , $($rest:tt)*) => {
-        path!(@build_seg [$($result,)* path!(@finish_seg [$($current)+])] [] $($rest)*)
+    // === Assemble: push every segment onto a PathBuf sized for the static ones ===
+    (@assemble $($seg:tt)*) => {{
+        let mut path = std::path::PathBuf::with_capacity($crate::__path_capacity!($($seg)*));
+        $($crate::path!(@push path $seg);)*
+        path
+    }};
+
+    // === `as String`: like `path_str!` in the native style, but anchors are resolved ===
+    (@push_string $path:ident $style:ident [anchor $name:ident]) => {
+        $style.push(
+            &mut $path,
+            $crate::__private::expect_anchor($crate::__private::Anchor::$name)
+                .to_str()
+                .expect("path!: the base directory is not valid UTF-8"),
+        )
     };
 
-    // When encountering a comma `,` but the current segment is empty, skip it
-    (@build_seg [$($result:expr),*] [] , $($rest:tt)*) => {
-        path!(@build_seg [$($result),*] [] $($rest)*)
+    (@push_string $path:ident $style:ident $seg:tt) => {
+        $crate::path_str!(@push $path $style $seg)
+    };
+
+    (@assemble_string $($seg:tt)*) => {{
+        let style = $crate::PathStyle::native();
+        let mut path = $crate::__private::String::with_capacity($crate::__path_capacity!($($seg)*));
+        $($crate::path!(@push_string path style $seg);)*
+        path
+    }};
+
+    // === Output type selectors ===
+    (as PathBuf: $($tokens:tt)*) => {
+        $crate::path!($($tokens)*)
     };
 
-    // Accumulate normal tokens into the current segment
-    (@build_seg [$($result:expr),*] [$($current:tt)*] $next:tt $($rest:tt)*) => {
-        path!(@build_seg [$($result),*] [$($current)* $next] $($rest)*)
+    (as String: $($tokens:tt)*) => {
+        $crate::__path_segments!([path @assemble_string] $($tokens)*)
     };
 
-    // End of tokens: process the final segment (if any)
-    (@build_seg [$($result:expr),*] [$($current:tt)+]) => {
-        vec![$($result,)* path!(@finish_seg [$($current)+])]
+    (as OsString: $($tokens:tt)*) => {
+        $crate::path!($($tokens)*).into_os_string()
     };
 
-    // End of tokens: no remaining segment
-    (@build_seg [$($result:expr),*] []) => {
-        vec![$($result),*]
+    (as Box<Path>: $($tokens:tt)*) => {
+        $crate::path!($($tokens)*).into_boxed_path()
     };
 
-    // === Helper: finalize one segment (stringify or return empty) ===
-    (@finish_seg []) => {
-        String::new()
+    (as Rc<Path>: $($tokens:tt)*) => {
+        std::rc::Rc::<std::path::Path>::from($crate::path!($($tokens)*))
     };
 
-    (@finish_seg [$($tokens:tt)+]) => {
-        stringify!($($tokens)+).to_string()
+    (as Arc<Path>: $($tokens:tt)*) => {
+        std::sync::Arc::<std::path::Path>::from($crate::path!($($tokens)*))
+    };
+
+    (as Utf8PathBuf: $($tokens:tt)*) => {
+        $crate::utf8_path!($($tokens)*)
+    };
+
+    // A misspelled or qualified selector must not quietly become part of the path
+    (as $($rest:tt)*) => {
+        ::core::compile_error!(
+            "unsupported `as` selector; expected one of `as PathBuf:`, `as String:`, `as OsString:`, \
+             `as Box<Path>:`, `as Rc<Path>:`, `as Arc<Path>:`, `as Utf8PathBuf:`"
+        )
     };
 
     // === Entry point ===
-    ($($tokens:tt)*) => {{
-        let segments: Vec<String> = path!(@build_seg [] [] $($tokens)*);
-        let mut path = std::path::PathBuf::new();
-        for seg in segments {
-            if !seg.is_empty() {
-                path.push(seg);
+    ($($tokens:tt)*) => {
+        $crate::__path_segments!([path @assemble] $($tokens)*)
+    };
+}
+
+/// Checked variant of [`path!`] for paths built from untrusted input.
+///
+/// Returns a `Result<`[`PathBuf`](std::path::PathBuf)`, `[`PathMacroError`]`>`.
+///
+/// Accepts the same syntax as [`path!`]. A first segment that is a plain
+/// `{expr}` value is the base directory and is pushed as-is, as are segments
+/// written in the macro call (identifiers, literals, a leading `/`). A leading
+/// `~` or `@name` anchor is the base directory too; when it cannot be
+/// determined the error has index 0 and [`ErrorKind::BaseUnavailable`]. Every
+/// other interpolated segment — `{expr}` after the first, `{%expr}`,
+/// `{?expr}`, each item of `{..expr}` and formatted segments like `user_{id}`,
+/// even in first position — must stay a single relative component. A segment
+/// is rejected when it:
+///
+/// - is empty (a `{?expr}` that is `None` is still skipped)
+/// - is absolute or starts with a root (`/etc/passwd`)
+/// - carries a Windows drive or UNC prefix (`C:`, `c:x`, `\\server\share`)
+/// - is or contains `..`, or is `.`
+/// - ends in a `.` or a space (`x.`), which Windows silently strips
+/// - contains a path separator (`a/b`, `a\b`)
+/// - contains a NUL byte
+/// - is a device name reserved on Windows (`CON`, `nul.txt`, `COM1`)
+/// - is longer than [`MAX_SEGMENT_LEN`] bytes
+///
+/// Windows prefixes, backslashes, trailing dots and reserved names are
+/// rejected on every platform so the path stays portable.
+///
+/// The returned [`PathMacroError`] reports the segment's position in the macro
+/// call, its value and the [`ErrorKind`]. Segment expressions after the first
+/// rejected one are not evaluated.
+///
+/// # Examples
+///
+/// ```rust
+/// use path_macro2::{try_path, ErrorKind};
+///
+/// let upload_dir = std::env::temp_dir();
+///
+/// let user_name = "alice";
+/// let ok = try_path!({upload_dir} / {user_name} / avatar.png);
+/// assert!(ok.is_ok());
+///
+/// let user_name = "../../etc/passwd";
+/// let err = try_path!({upload_dir} / {user_name} / avatar.png).unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::ParentDir);
+/// assert_eq!(err.index(), 1);
+///
+/// let user_name = "";
+/// let err = try_path!({upload_dir} / {user_name} / avatar.png).unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::Empty);
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! try_path {
+    // === Push one parsed segment, validating everything that was interpolated ===
+    (@push $path:ident $index:ident [str $($seg:tt)+]) => {{
+        let seg: &str = $($seg)+;
+        if !seg.is_empty() {
+            $path.push(seg);
+        }
+        Ok(())
+    }};
+
+    (@push $path:ident $index:ident [string $($seg:tt)+]) => {
+        $crate::__private::push_checked(&mut $path, $index, AsRef::<std::path::Path>::as_ref(&$($seg)+))
+    };
+
+    (@push $path:ident $index:ident [path $($expr:tt)+]) => {
+        $crate::__private::push_checked(&mut $path, $index, AsRef::<std::path::Path>::as_ref(&($($expr)+)))
+    };
+
+    (@push $path:ident $index:ident [opt $($expr:tt)+]) => {
+        match &($($expr)+) {
+            Some(seg) => $crate::__private::push_checked(&mut $path, $index, AsRef::<std::path::Path>::as_ref(seg)),
+            None => Ok(()),
+        }
+    };
+
+    (@push $path:ident $index:ident [spread $($expr:tt)+]) => {
+        IntoIterator::into_iter($($expr)+).try_for_each(|seg| {
+            $crate::__private::push_checked(&mut $path, $index, AsRef::<std::path::Path>::as_ref(&seg))
+        })
+    };
+
+    // === Check and push segments until the first error ===
+    (@check $path:ident $start:literal $($seg:tt)*) => {{
+        let mut result: Result<(), $crate::PathMacroError> = Ok(());
+        let mut index: usize = $start;
+        $(if result.is_ok() {
+            result = $crate::try_path!(@push $path index $seg);
+            index += 1;
+        })*
+        result.map(|()| $path)
+    }};
+
+    // === Assemble: a leading root is trusted, every segment after it is checked ===
+    (@assemble [root] $($seg:tt)*) => {{
+        let mut path = std::path::PathBuf::with_capacity($crate::__path_capacity!([root] $($seg)*));
+        path.push(std::path::Component::RootDir);
+        $crate::try_path!(@check path 0 $($seg)*)
+    }};
+
+    // === Assemble: an anchor's directory is the trusted base, every later one is checked ===
+    (@assemble [anchor $name:ident] $($seg:tt)*) => {
+        match $crate::__private::try_anchor($crate::__private::Anchor::$name) {
+            Ok(mut path) => $crate::try_path!(@check path 1 $($seg)*),
+            Err(err) => Err(err),
+        }
+    };
+
+    // === Assemble: a leading `{expr}` or static segment is the trusted base, every later one is checked ===
+    (@assemble [path $($base:tt)+] $($seg:tt)*) => {{
+        let mut path = std::path::PathBuf::with_capacity($crate::__path_capacity!([path $($base)+] $($seg)*));
+        $crate::path!(@push path [path $($base)+]);
+        $crate::try_path!(@check path 1 $($seg)*)
+    }};
+
+    (@assemble [str $($base:tt)+] $($seg:tt)*) => {{
+        let mut path = std::path::PathBuf::with_capacity($crate::__path_capacity!([str $($base)+] $($seg)*));
+        $crate::path!(@push path [str $($base)+]);
+        $crate::try_path!(@check path 1 $($seg)*)
+    }};
+
+    (@assemble) => {
+        Ok::<_, $crate::PathMacroError>(std::path::PathBuf::new())
+    };
+
+    // === Assemble: any other leading segment (`{..expr}`, `{?expr}`, `user_{id}`) is checked too ===
+    (@assemble $($seg:tt)+) => {{
+        let mut path = std::path::PathBuf::with_capacity($crate::__path_capacity!($($seg)+));
+        $crate::try_path!(@check path 0 $($seg)+)
+    }};
+
+    // === Entry point ===
+    ($($tokens:tt)*) => {
+        $crate::__path_segments!([try_path @assemble] $($tokens)*)
+    };
+}
+
+/// [`path!`] building a [`camino::Utf8PathBuf`] (requires the `camino` feature).
+///
+/// Accepts the same syntax as [`path!`]. Every segment is string-based, so the
+/// result is valid UTF-8 by construction and no fallible conversion is needed:
+/// `{expr}`, `{?expr}` and the items of `{..expr}` accept anything
+/// `AsRef<Utf8Path>` (`&str`, `String`, `Utf8PathBuf`, `&Utf8Path`), and
+/// `{%expr}` and interpolated segments are formatted to `String`.
+///
+/// # Examples
+///
+/// ```rust
+/// use camino::{Utf8Path, Utf8PathBuf};
+/// use path_macro2::utf8_path;
+///
+/// let root = Utf8PathBuf::from("workspace");
+/// let krate = "my-crate";
+/// let manifest: Utf8PathBuf = utf8_path!({root} / crates / {krate} / Cargo.toml);
+/// assert_eq!(manifest, Utf8Path::new("workspace").join("crates/my-crate/Cargo.toml"));
+/// ```
+#[cfg(feature = "camino")]
+#[macro_export]
+macro_rules! utf8_path {
+    // === Push one parsed segment, skipping empty ones ===
+    (@push $path:ident [root]) => {
+        $path.push($crate::__private::camino::Utf8Component::RootDir)
+    };
+
+    (@push $path:ident [anchor $name:ident]) => {
+        $path.push(
+            $crate::__private::camino::Utf8PathBuf::from_path_buf(
+                $crate::__private::expect_anchor($crate::__private::Anchor::$name),
+            )
+            .expect("utf8_path!: the base directory is not valid UTF-8"),
+        )
+    };
+
+    (@push $path:ident [str $($seg:tt)+]) => {{
+        let seg: &str = $($seg)+;
+        if !seg.is_empty() {
+            $path.push(seg);
+        }
+    }};
+
+    (@push $path:ident [string $($seg:tt)+]) => {{
+        let seg: $crate::__private::String = $($seg)+;
+        if !seg.is_empty() {
+            $path.push(seg);
+        }
+    }};
+
+    (@push $path:ident [path $($expr:tt)+]) => {
+        match AsRef::<$crate::__private::camino::Utf8Path>::as_ref(&($($expr)+)) {
+            seg => if !seg.as_str().is_empty() {
+                $path.push(seg);
+            },
+        }
+    };
+
+    (@push $path:ident [opt $($expr:tt)+]) => {
+        if let Some(seg) = &($($expr)+) {
+            let seg = AsRef::<$crate::__private::camino::Utf8Path>::as_ref(seg);
+            if !seg.as_str().is_empty() {
+                $path.push(seg);
+            }
+        }
+    };
+
+    (@push $path:ident [spread $($expr:tt)+]) => {
+        for seg in $($expr)+ {
+            let seg = AsRef::<$crate::__private::camino::Utf8Path>::as_ref(&seg);
+            if !seg.as_str().is_empty() {
+                $path.push(seg);
             }
         }
+    };
+
+    // === Assemble: push every segment onto a Utf8PathBuf sized for the static ones ===
+    (@assemble $($seg:tt)*) => {{
+        let mut path = $crate::__private::camino::Utf8PathBuf::with_capacity($crate::__path_capacity!($($seg)*));
+        $($crate::utf8_path!(@push path $seg);)*
+        path
+    }};
+
+    // === Entry point ===
+    ($($tokens:tt)*) => {
+        $crate::__path_segments!([utf8_path @assemble] $($tokens)*)
+    };
+}
+
+/// Builds a path `String` in an explicit [`PathStyle`], independent of the host.
+///
+/// Takes a style expression, a `;`, then the same segment syntax as [`path!`].
+/// Segments are joined with the style's separator and root rules (see
+/// [`PathStyle::push`]), so the result does not depend on the platform the
+/// code runs on. Interpolated values are string-based: `{expr}`, `{?expr}`
+/// and the items of `{..expr}` accept `AsRef<str>`; use `{%expr}` for
+/// `Display` values such as `path.display()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use path_macro2::{path_str, PathStyle};
+///
+/// let config = "release";
+/// let win = path_str!(PathStyle::Windows; target / {config} / app.exe);
+/// assert_eq!(win, "target\\release\\app.exe");
+///
+/// let unix = path_str!(PathStyle::Unix; / usr / lib / {config});
+/// assert_eq!(unix, "/usr/lib/release");
+///
+/// let mixed = path_str!(PathStyle::WindowsSlash; "C:\\" / "Program Files" / app);
+/// assert_eq!(mixed, "C:/Program Files/app");
+///
+/// // In a build script, follow the target rather than the host
+/// let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+/// let style = PathStyle::from_target_os(&target_os);
+/// let def_flag = format!("/DEF:{}", path_str!(style; vendor / dll / windivert.def));
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! path_str {
+    // === Push one parsed segment, skipping empty ones ===
+    (@push $path:ident $style:ident [root]) => {
+        $path.push($style.separator())
+    };
+
+    (@push $path:ident $style:ident [anchor $name:ident]) => {
+        ::core::compile_error!("path_str! builds paths for another platform and does not support `~` or `@` anchors")
+    };
+
+    (@push $path:ident $style:ident [str $($seg:tt)+]) => {
+        $style.push(&mut $path, $($seg)+)
+    };
+
+    (@push $path:ident $style:ident [string $($seg:tt)+]) => {
+        $style.push(&mut $path, &$($seg)+)
+    };
+
+    (@push $path:ident $style:ident [path $($expr:tt)+]) => {
+        $style.push(&mut $path, AsRef::<str>::as_ref(&($($expr)+)))
+    };
+
+    (@push $path:ident $style:ident [opt $($expr:tt)+]) => {
+        if let Some(seg) = &($($expr)+) {
+            $style.push(&mut $path, AsRef::<str>::as_ref(seg));
+        }
+    };
+
+    (@push $path:ident $style:ident [spread $($expr:tt)+]) => {
+        for seg in $($expr)+ {
+            $style.push(&mut $path, AsRef::<str>::as_ref(&seg));
+        }
+    };
+
+    // === Assemble: push every segment onto a String sized for the static ones ===
+    (@assemble ($($style:tt)+) $($seg:tt)*) => {{
+        let style: $crate::PathStyle = $($style)+;
+        let mut path = $crate::__private::String::with_capacity($crate::__path_capacity!($($seg)*));
+        $($crate::path_str!(@push path style $seg);)*
         path
     }};
+
+    // === Entry point ===
+    ($style:expr; $($tokens:tt)*) => {
+        $crate::__path_segments!([path_str @assemble ($style)] $($tokens)*)
+    };
 }
 
 /// Cross-platform path constant macro that generates `&'static str`.
@@ -135,13 +652,35 @@ macro_rules! path {
 /// - Windows: uses backslash `\`
 /// - Unix/Linux/macOS: uses forward slash `/`
 ///
+/// The platform is the target of the crate being compiled. In a `build.rs`
+/// that is the host, so build scripts that emit paths for the cross-compilation
+/// target should use [`path_const_windows!`] or [`path_const_unix!`] instead.
+///
 /// # Supported Syntax
 ///
 /// - `path_const!(a / b / c)` — slash separators
 /// - `path_const!(a, b, c)` — comma separators
+/// - `path_const!(/ usr / lib)` — leading `/` anchors at the filesystem root
 /// - Identifiers: `vendor`, `dll`
 /// - Dotted identifiers: `file.txt`, `windivert.c`
+/// - Hyphenated and digit-leading names: `my-app`, `2024-01-01`, `v1.2.3`
 /// - String literals: `"my folder"`, `"file name.txt"`
+/// - Macro calls that expand to a string literal, in braces:
+///   `{env!("CARGO_MANIFEST_DIR")}`, `{concat!("lib", "foo")}`
+/// - `&'static str` constants, in braces: `{BASE_DIR}`, `{dirs::VENDOR}`
+///
+/// With only literal segments (including braced macro calls) the result is a
+/// string literal that can be nested in `concat!`. A `const` item segment
+/// makes the result a const-evaluated `&'static str` instead: it still
+/// works in `const` and `static` items but not inside `concat!`. Like
+/// `concat!` itself, neither form accepts runtime values; use [`path!`] for
+/// those. For the same reason a leading `~` or `@name` anchor fails to
+/// compile.
+///
+/// Like `PathBuf::push`, no separator is added after a segment that already
+/// ends in one: a leading `/`, a root such as `"/"`, `"\\\\"` or `"C:\\"`, or
+/// any other literal or `{CONST}` value ending in `/` or `\` (`"/usr/" / bin`
+/// gives `/usr/bin`).
 ///
 /// # Examples
 ///
@@ -150,115 +689,742 @@ macro_rules! path {
 /// const CONFIG_PATH: &str = path_const!(config / app.toml);
 /// const LIB_PATH: &str = path_const!(vendor, dll, windivert.c);
 /// const DEF_FLAG: &str = concat!("/DEF:", path_const!(vendor / dll / windivert.def));
+///
+/// const INCLUDE_DIR: &str = path_const!({env!("CARGO_MANIFEST_DIR")} / vendor / include);
+/// const VENDOR: &str = "vendor";
+/// const SOURCE: &str = path_const!({VENDOR} / dll / windivert.c);
 /// ```
-#[cfg(target_os = "windows")]
 #[macro_export]
 macro_rules! path_const {
-    // === Internal: Build segments ===
-    // String literal followed by slash → add as segment and continue
-    (@build [$($result:expr),*] [] $lit:literal / $($rest:tt)*) => {
-        path_const!(@build [$($result,)* $lit] [] $($rest)*)
+    ($($tokens:tt)*) => {
+        $crate::__path_const_native!($($tokens)*)
     };
-    // String literal followed by comma → add as segment and continue
-    (@build [$($result:expr),*] [] $lit:literal , $($rest:tt)*) => {
-        path_const!(@build [$($result,)* $lit] [] $($rest)*)
+}
+
+#[cfg(target_os = "windows")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __path_const_native {
+    ($($tokens:tt)*) => {
+        $crate::path_const_windows!($($tokens)*)
     };
-    // String literal at end → add as segment
-    (@build [$($result:expr),*] [] $lit:literal) => {
-        path_const!(@concat $($result,)* $lit)
+}
+
+#[cfg(not(target_os = "windows"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __path_const_native {
+    ($($tokens:tt)*) => {
+        $crate::path_const_unix!($($tokens)*)
     };
-    // Slash `/` → complete current segment
-    (@build [$($result:expr),*] [$($current:tt)+] / $($rest:tt)*) => {
-        path_const!(@build [$($result,)* path_const!(@finish [$($current)+])] [] $($rest)*)
+}
+
+/// [`path_const!`] with Windows separators (`\`) on every platform.
+///
+/// Useful in build scripts, where `cfg(target_os)` describes the host rather
+/// than the cross-compilation target. Pick the style from
+/// `CARGO_CFG_TARGET_OS` instead:
+///
+/// ```rust
+/// use path_macro2::{path_const_unix, path_const_windows};
+///
+/// const DEF_FLAG: &str = concat!("/DEF:", path_const_windows!(vendor / dll / windivert.def));
+/// assert_eq!(DEF_FLAG, "/DEF:vendor\\dll\\windivert.def");
+///
+/// let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+/// let source = if target_os == "windows" {
+///     path_const_windows!(vendor / dll / windivert.c)
+/// } else {
+///     path_const_unix!(vendor / dll / windivert.c)
+/// };
+/// ```
+#[macro_export]
+macro_rules! path_const_windows {
+    ($($tokens:tt)*) => {
+        $crate::__path_const!("\\"; $($tokens)*)
     };
-    (@build [$($result:expr),*] [] / $($rest:tt)*) => {
-        path_const!(@build [$($result),*] [] $($rest)*)
+}
+
+/// [`path_const!`] with Unix separators (`/`) on every platform.
+///
+/// See [`path_const_windows!`] for when to prefer an explicit style.
+///
+/// ```rust
+/// use path_macro2::path_const_unix;
+///
+/// const INCLUDE_DIR: &str = path_const_unix!(/ usr / local / include);
+/// assert_eq!(INCLUDE_DIR, "/usr/local/include");
+/// ```
+#[macro_export]
+macro_rules! path_const_unix {
+    ($($tokens:tt)*) => {
+        $crate::__path_const!("/"; $($tokens)*)
     };
-    // Comma `,` → complete current segment
-    (@build [$($result:expr),*] [$($current:tt)+] , $($rest:tt)*) => {
-        path_const!(@build [$($result,)* path_const!(@finish [$($current)+])] [] $($rest)*)
+}
+
+/// [`path!`] anchored at the calling crate's `CARGO_MANIFEST_DIR`.
+///
+/// Accepts the same syntax as [`path!`] and returns a [`PathBuf`](std::path::PathBuf)
+/// under the directory containing the `Cargo.toml` of the crate that invokes
+/// the macro, as captured at compile time. Handy for test fixtures.
+///
+/// A leading `as Type:` selects the output type as in [`path!`]; a leading
+/// `~` or `@name` anchor is rejected, since the manifest directory is already
+/// the base.
+///
+/// See [`manifest_path_const!`] for a `&'static str` built at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use path_macro2::manifest_path;
+///
+/// let name = "Cargo.toml";
+/// let manifest = manifest_path!({name});
+/// assert!(manifest.is_absolute());
+/// assert_eq!(manifest, std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"));
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! manifest_path {
+    // === Output type selectors, passed through to `path!` ===
+    (as $ty:ident: $($tokens:tt)*) => {
+        $crate::path!(as $ty: {::core::env!("CARGO_MANIFEST_DIR")} / $($tokens)*)
     };
-    (@build [$($result:expr),*] [] , $($rest:tt)*) => {
-        path_const!(@build [$($result),*] [] $($rest)*)
+    (as $ptr:ident<Path>: $($tokens:tt)*) => {
+        $crate::path!(as $ptr<Path>: {::core::env!("CARGO_MANIFEST_DIR")} / $($tokens)*)
     };
-    // Accumulate tokens (including `.`)
-    (@build [$($result:expr),*] [$($current:tt)*] $next:tt $($rest:tt)*) => {
-        path_const!(@build [$($result),*] [$($current)* $next] $($rest)*)
+    (as $($rest:tt)*) => {
+        $crate::path!(as $($rest)*)
     };
-    // End: finalize last segment
-    (@build [$($result:expr),*] [$($current:tt)+]) => {
-        path_const!(@concat $($result,)* path_const!(@finish [$($current)+]))
+
+    // === The manifest directory is the base, so there is no room for another ===
+    (~ $($tokens:tt)*) => {
+        ::core::compile_error!("manifest_path! is anchored at CARGO_MANIFEST_DIR and does not support `~`")
     };
-    (@build [$($result:expr),*] []) => {
-        path_const!(@concat $($result),*)
+    (@ $($tokens:tt)*) => {
+        ::core::compile_error!("manifest_path! is anchored at CARGO_MANIFEST_DIR and does not support `@` anchors")
     };
-    // === Helper: Finalize one segment ===
-    (@finish []) => { "" };
-    (@finish [$($tokens:tt)+]) => { stringify!($($tokens)+) };
-    // === Concat with platform separators ===
-    (@concat) => { "" };
-    (@concat $single:expr) => { $single };
-    (@concat $first:expr, $($rest:expr),+) => {
-        concat!($first, "\\", path_const!(@concat $($rest),+))
+
+    ($($tokens:tt)*) => {
+        $crate::path!({::core::env!("CARGO_MANIFEST_DIR")} / $($tokens)*)
     };
-    // === Entry point ===
+}
+
+/// [`path_const!`] anchored at the calling crate's `CARGO_MANIFEST_DIR`.
+///
+/// Accepts the same syntax as [`path_const!`] and yields an absolute
+/// `&'static str` built at compile time, using the platform separators. A
+/// leading `~` or `@name` anchor is rejected, since the manifest directory is
+/// already the base.
+///
+/// # Examples
+///
+/// ```rust
+/// use path_macro2::manifest_path_const;
+///
+/// const MANIFEST: &str = manifest_path_const!(Cargo.toml);
+/// assert!(std::path::Path::new(MANIFEST).is_file());
+/// ```
+#[macro_export]
+macro_rules! manifest_path_const {
+    // === The manifest directory is the base, so there is no room for another ===
+    (~ $($tokens:tt)*) => {
+        ::core::compile_error!("manifest_path_const! is anchored at CARGO_MANIFEST_DIR and does not support `~`")
+    };
+    (@ $($tokens:tt)*) => {
+        ::core::compile_error!("manifest_path_const! is anchored at CARGO_MANIFEST_DIR and does not support `@` anchors")
+    };
+
     ($($tokens:tt)*) => {
-        path_const!(@build [] [] $($tokens)*)
+        $crate::path_const!({::core::env!("CARGO_MANIFEST_DIR")} / $($tokens)*)
     };
 }
 
-#[cfg(not(target_os = "windows"))]
+/// [`include_str!`] for a path written in the [`path_const!`] syntax, relative
+/// to the calling crate's `CARGO_MANIFEST_DIR`.
+///
+/// The path is joined with `/`, which every host accepts, so the same call
+/// works when building on Windows. Segments may be literals, identifiers and
+/// braced macro calls such as `{env!("ASSET_DIR")}`; `const` item segments
+/// are not supported because `include_str!` needs a literal, and neither are
+/// leading `~` or `@name` anchors.
+///
+/// # Examples
+///
+/// ```rust
+/// use path_macro2::include_path_str;
+///
+/// const README: &str = include_path_str!("README.md");
+/// assert!(README.starts_with("# path_macro2"));
+/// ```
 #[macro_export]
-macro_rules! path_const {
-    // === Internal: Build segments ===
-    // String literal followed by slash → add as segment and continue
-    (@build [$($result:expr),*] [] $lit:literal / $($rest:tt)*) => {
-        path_const!(@build [$($result,)* $lit] [] $($rest)*)
+macro_rules! include_path_str {
+    // === The manifest directory is the base, so there is no room for another ===
+    (~ $($tokens:tt)*) => {
+        ::core::compile_error!("include_path_str! is anchored at CARGO_MANIFEST_DIR and does not support `~`")
+    };
+    (@ $($tokens:tt)*) => {
+        ::core::compile_error!("include_path_str! is anchored at CARGO_MANIFEST_DIR and does not support `@` anchors")
+    };
+
+    ($($tokens:tt)*) => {
+        ::core::include_str!($crate::path_const_unix!({::core::env!("CARGO_MANIFEST_DIR")} / $($tokens)*))
+    };
+}
+
+/// [`include_bytes!`] for a path written in the [`path_const!`] syntax,
+/// relative to the calling crate's `CARGO_MANIFEST_DIR`.
+///
+/// See [`include_path_str!`] for the accepted segments.
+///
+/// # Examples
+///
+/// ```rust
+/// use path_macro2::include_path_bytes;
+///
+/// static LICENSE: &[u8] = include_path_bytes!(LICENSE-MIT);
+/// assert!(LICENSE.starts_with(b"Copyright"));
+/// ```
+#[macro_export]
+macro_rules! include_path_bytes {
+    // === The manifest directory is the base, so there is no room for another ===
+    (~ $($tokens:tt)*) => {
+        ::core::compile_error!("include_path_bytes! is anchored at CARGO_MANIFEST_DIR and does not support `~`")
+    };
+    (@ $($tokens:tt)*) => {
+        ::core::compile_error!("include_path_bytes! is anchored at CARGO_MANIFEST_DIR and does not support `@` anchors")
+    };
+
+    ($($tokens:tt)*) => {
+        ::core::include_bytes!($crate::path_const_unix!({::core::env!("CARGO_MANIFEST_DIR")} / $($tokens)*))
+    };
+}
+
+/// Prints `cargo:rerun-if-changed` directives for build script inputs declared
+/// with the [`path_const!`] syntax.
+///
+/// Takes one or more paths separated by commas, so segments inside a path are
+/// separated with `/`. Each path is printed with the separators of the platform
+/// the build script runs on, and the macro evaluates to the paths as a
+/// `[&'static str; N]` array, so the watched inputs can be reused without
+/// being declared twice. See [`rerun_if_changed()`] for paths built at
+/// runtime.
+///
+/// # Examples
+///
+/// ```rust
+/// use path_macro2::rerun_if_changed;
+///
+/// // build.rs
+/// let [source, include_dir] = rerun_if_changed!(vendor / dll / windivert.c, vendor / include);
+/// // cargo:rerun-if-changed=vendor/dll/windivert.c
+/// // cargo:rerun-if-changed=vendor/include
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! rerun_if_changed {
+    // === Split paths at top-level commas ===
+    (@split [$($paths:tt)*] [$($current:tt)+] , $($rest:tt)*) => {
+        $crate::rerun_if_changed!(@split [$($paths)* [$($current)+]] [] $($rest)*)
+    };
+    (@split $paths:tt [$($current:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::rerun_if_changed!(@split $paths [$($current)* $next] $($rest)*)
+    };
+    (@split [$($paths:tt)*] [$($current:tt)+]) => {
+        $crate::rerun_if_changed!(@emit $($paths)* [$($current)+])
+    };
+    (@split [$($paths:tt)*] []) => {
+        $crate::rerun_if_changed!(@emit $($paths)*)
     };
-    // String literal followed by comma → add as segment and continue
-    (@build [$($result:expr),*] [] $lit:literal , $($rest:tt)*) => {
-        path_const!(@build [$($result,)* $lit] [] $($rest)*)
+
+    // === Print every path and return them ===
+    (@emit $([$($path:tt)+])*) => {{
+        let paths: [&'static str; 0 $(+ $crate::rerun_if_changed!(@one $($path)+))*] =
+            [$($crate::path_const!($($path)+)),*];
+        $crate::rerun_if_changed(paths);
+        paths
+    }};
+    (@one $($path:tt)+) => { 1 };
+
+    // === Entry point ===
+    ($($tokens:tt)*) => {
+        $crate::rerun_if_changed!(@split [] [] $($tokens)*)
     };
-    // String literal at end → add as segment
-    (@build [$($result:expr),*] [] $lit:literal) => {
-        path_const!(@concat $($result,)* $lit)
+}
+
+/// [`path_const!`] as a [`ConstPath`], a `&'static Path` that can be declared
+/// in `const` and `static` items.
+///
+/// Accepts the same syntax as [`path_const!`] and uses the same platform
+/// separators.
+///
+/// # Examples
+///
+/// ```rust
+/// use path_macro2::{static_path, ConstPath};
+/// use std::path::Path;
+///
+/// static CONFIG: ConstPath = static_path!(config / app.toml);
+///
+/// let config: &'static Path = CONFIG.as_path();
+/// assert_eq!(config.extension().unwrap(), "toml");
+/// assert_eq!(CONFIG.file_name().unwrap(), "app.toml");
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! static_path {
+    ($($tokens:tt)*) => {
+        $crate::ConstPath::new($crate::path_const!($($tokens)*))
+    };
+}
+
+/// [`path_const!`] as a NUL-terminated `&'static CStr`, for passing constant
+/// paths to C libraries.
+///
+/// Accepts the same syntax as [`path_const!`], including `{CONST}` segments,
+/// and uses the same platform separators. The string is checked at compile
+/// time: a segment containing a NUL byte fails to compile. Usable in `const`
+/// and `static` items. Without the `std` feature the macro expands to
+/// [`core::ffi::CStr`], which needs Rust 1.64 or newer.
+///
+/// For paths with interpolated values, see [`cstring_path!`].
+///
+/// # Examples
+///
+/// ```rust
+/// use path_macro2::cpath;
+/// use std::ffi::CStr;
+///
+/// const CONFIG: &CStr = cpath!(/ etc / myapp / config.toml);
+///
+/// #[cfg(not(target_os = "windows"))]
+/// assert_eq!(CONFIG.to_bytes(), b"/etc/myapp/config.toml");
+/// ```
+#[macro_export]
+macro_rules! cpath {
+    ($($tokens:tt)*) => {{
+        // Appended by const evaluation, so `{CONST}` segments work as in `path_const!`
+        const PARTS: &[&str] = &[$crate::path_const!($($tokens)*), "\0"];
+        const BYTES: [u8; $crate::__private::joined_len("", PARTS)] =
+            $crate::__private::join_segments("", PARTS);
+        $crate::__cpath_from_bytes!(BYTES)
+    }};
+}
+
+/// Turns the checked bytes of a `cpath!` into a `&'static CStr`.
+///
+/// `CStr::from_bytes_with_nul` is only `const` since Rust 1.72, so the bytes
+/// are checked by `is_c_str` and converted with the unchecked constructor.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cpath_from_bytes {
+    ($bytes:ident) => {{
+        const CPATH: &::std::ffi::CStr = if $crate::__private::is_c_str(&$bytes) {
+            // SAFETY: `is_c_str` checked that the only NUL byte is the last one
+            unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(&$bytes) }
+        } else {
+            ::core::panic!("cpath! segments must not contain NUL bytes")
+        };
+        CPATH
+    }};
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cpath_from_bytes {
+    ($bytes:ident) => {{
+        const CPATH: &::core::ffi::CStr = if $crate::__private::is_c_str(&$bytes) {
+            // SAFETY: `is_c_str` checked that the only NUL byte is the last one
+            unsafe { ::core::ffi::CStr::from_bytes_with_nul_unchecked(&$bytes) }
+        } else {
+            ::core::panic!("cpath! segments must not contain NUL bytes")
+        };
+        CPATH
+    }};
+}
+
+/// [`path!`] as an owned [`CString`](std::ffi::CString), for passing runtime
+/// paths to C libraries.
+///
+/// Accepts the same syntax as [`path!`], including interpolation, and returns
+/// `Result<CString, `[`NulError`](std::ffi::NulError)`>` because interpolated
+/// values may contain NUL bytes. On Unix the path's bytes are passed through
+/// unchanged; elsewhere the path is converted to UTF-8, replacing anything
+/// that is not valid Unicode.
+///
+/// # Examples
+///
+/// ```rust
+/// use path_macro2::cstring_path;
+///
+/// let user = "alice";
+/// let home = cstring_path!(/ home / {user} / ".profile").unwrap();
+///
+/// #[cfg(not(target_os = "windows"))]
+/// assert_eq!(home.as_bytes(), b"/home/alice/.profile");
+///
+/// let user = "bad\0name";
+/// assert!(cstring_path!(/ home / {user}).is_err());
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! cstring_path {
+    ($($tokens:tt)*) => {
+        $crate::__private::into_cstring($crate::path!($($tokens)*))
     };
+}
+
+/// [`path_const_windows!`] as a NUL-terminated UTF-16 `&'static [u16]`, for
+/// wide-character Win32 APIs such as `CreateFileW`.
+///
+/// Accepts the same syntax as [`path_const!`], including `{CONST}` segments,
+/// and always uses Windows separators, so the result is the same on every host
+/// and can be built and tested on Linux. The array is encoded at compile time
+/// and can be used in `const` and `static` items; a segment containing a NUL
+/// byte fails to compile.
+///
+/// # Examples
+///
+/// ```rust
+/// use path_macro2::wpath;
+///
+/// const DRIVER: &[u16] = wpath!("C:\\" / Windows / System32 / drivers / WinDivert64.sys);
+///
+/// let expected: Vec<u16> = "C:\\Windows\\System32\\drivers\\WinDivert64.sys\0"
+///     .encode_utf16()
+///     .collect();
+/// assert_eq!(DRIVER, &expected[..]);
+/// assert_eq!(DRIVER.last(), Some(&0));
+/// ```
+#[macro_export]
+macro_rules! wpath {
+    ($($tokens:tt)*) => {{
+        const PATH: &str = $crate::path_const_windows!($($tokens)*);
+        const WIDE: [u16; $crate::__private::utf16_len(PATH) + 1] = $crate::__private::encode_wide_path(PATH);
+        const WPATH: &[u16] = &WIDE;
+        WPATH
+    }};
+}
+
+/// Shared implementation of the `path_const!` family, joining with `$sep`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __path_const {
+    // === Internal: Build segments (raw tokens, one bracket group per segment) ===
     // Slash `/` → complete current segment
-    (@build [$($result:expr),*] [$($current:tt)+] / $($rest:tt)*) => {
-        path_const!(@build [$($result,)* path_const!(@finish [$($current)+])] [] $($rest)*)
+    (@build $sep:literal [$($done:tt)*] [$($current:tt)+] / $($rest:tt)*) => {
+        $crate::__path_const!(@build $sep [$($done)* [$($current)+]] [] $($rest)*)
     };
-    (@build [$($result:expr),*] [] / $($rest:tt)*) => {
-        path_const!(@build [$($result),*] [] $($rest)*)
+    (@build $sep:literal [$($done:tt)*] [] / $($rest:tt)*) => {
+        $crate::__path_const!(@build $sep [$($done)*] [] $($rest)*)
     };
     // Comma `,` → complete current segment
-    (@build [$($result:expr),*] [$($current:tt)+] , $($rest:tt)*) => {
-        path_const!(@build [$($result,)* path_const!(@finish [$($current)+])] [] $($rest)*)
+    (@build $sep:literal [$($done:tt)*] [$($current:tt)+] , $($rest:tt)*) => {
+        $crate::__path_const!(@build $sep [$($done)* [$($current)+]] [] $($rest)*)
     };
-    (@build [$($result:expr),*] [] , $($rest:tt)*) => {
-        path_const!(@build [$($result),*] [] $($rest)*)
+    (@build $sep:literal [$($done:tt)*] [] , $($rest:tt)*) => {
+        $crate::__path_const!(@build $sep [$($done)*] [] $($rest)*)
     };
     // Accumulate tokens (including `.`)
-    (@build [$($result:expr),*] [$($current:tt)*] $next:tt $($rest:tt)*) => {
-        path_const!(@build [$($result),*] [$($current)* $next] $($rest)*)
+    (@build $sep:literal [$($done:tt)*] [$($current:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__path_const!(@build $sep [$($done)*] [$($current)* $next] $($rest)*)
     };
     // End: finalize last segment
-    (@build [$($result:expr),*] [$($current:tt)+]) => {
-        path_const!(@concat $($result,)* path_const!(@finish [$($current)+]))
+    (@build $sep:literal [$($done:tt)*] [$($current:tt)+]) => {
+        $crate::__path_const!(@scan $sep [$($done)* [$($current)+]] $($done)* [$($current)+])
+    };
+    (@build $sep:literal [$($done:tt)*] []) => {
+        $crate::__path_const!(@scan $sep [$($done)*] $($done)*)
     };
-    (@build [$($result:expr),*] []) => {
-        path_const!(@concat $($result),*)
+    // === Pick literal or const-evaluated joining ===
+    // Braced macro calls (`{env!(..)}`) expand to literals, so `concat!` still works
+    (@scan $sep:literal $all:tt [{ $(::)? $($mac:ident)::+ ! $args:tt }] $($rest:tt)*) => {
+        $crate::__path_const!(@scan $sep $all $($rest)*)
+    };
+    // Any other braced segment names a `const` item, which `concat!` cannot read
+    (@scan $sep:literal [$($all:tt)*] [{ $($expr:tt)+ }] $($rest:tt)*) => {
+        $crate::__path_const!(@join $sep $($all)*)
+    };
+    (@scan $sep:literal $all:tt $seg:tt $($rest:tt)*) => {
+        $crate::__path_const!(@scan $sep $all $($rest)*)
+    };
+    (@scan $sep:literal [$($all:tt)*]) => {
+        $crate::__path_const!(@concat $sep $($all)*)
     };
     // === Helper: Finalize one segment ===
-    (@finish []) => { "" };
-    (@finish [$($tokens:tt)+]) => { stringify!($($tokens)+) };
-    // === Concat with platform separators ===
-    (@concat) => { "" };
-    (@concat $single:expr) => { $single };
-    (@concat $first:expr, $($rest:expr),+) => {
-        concat!($first, "/", path_const!(@concat $($rest),+))
+    // The root is the separator, braced segments are used as written, a lone
+    // literal keeps its value or spelling, anything else is stringified
+    (@finish $sep:literal /) => { $sep };
+    (@finish $sep:literal { $($expr:tt)+ }) => { $($expr)+ };
+    (@finish $sep:literal $lit:literal) => { $crate::__private::literal_segment!($lit) };
+    (@finish $sep:literal $($tokens:tt)+) => { concat!($(stringify!($tokens)),+) };
+    // === Concat with separators (skipped after segments ending in one) ===
+    (@concat $sep:literal) => { "" };
+    (@concat $sep:literal [$($single:tt)+]) => { $crate::__path_const!(@finish $sep $($single)+) };
+    (@concat $sep:literal [$($first:tt)+] $($rest:tt)+) => {
+        concat!(
+            $crate::__path_const!(@finish $sep $($first)+),
+            $crate::__private::segment_separator!($sep; $($first)+),
+            $crate::__path_const!(@concat $sep $($rest)+)
+        )
     };
+    // === Join by const evaluation (skipping separators after segments ending in one) ===
+    (@join $sep:literal $([$($seg:tt)+])*) => {{
+        const SEGMENTS: &[&str] = &[$($crate::__path_const!(@finish $sep $($seg)+)),*];
+        const BYTES: [u8; $crate::__private::joined_len($sep, SEGMENTS)] =
+            $crate::__private::join_segments($sep, SEGMENTS);
+        const JOINED: &str = match ::core::str::from_utf8(&BYTES) {
+            ::core::result::Result::Ok(joined) => joined,
+            ::core::result::Result::Err(_) => ::core::panic!("path_const! segments must be valid UTF-8"),
+        };
+        JOINED
+    }};
     // === Entry point ===
-    ($($tokens:tt)*) => {
-        path_const!(@build [] [] $($tokens)*)
+    // Leading anchors need the runtime environment, which a constant cannot read
+    ($sep:literal; ~ $($tokens:tt)*) => {
+        ::core::compile_error!("path_const! is built at compile time and does not support `~`; use path! instead")
+    };
+    ($sep:literal; @ $($tokens:tt)*) => {
+        ::core::compile_error!("path_const! is built at compile time and does not support `@` anchors; use path! instead")
+    };
+    // Leading slash `/` → start from the filesystem root
+    ($sep:literal; / $($tokens:tt)*) => {
+        $crate::__path_const!(@build $sep [[/]] [] $($tokens)*)
+    };
+    ($sep:literal; $($tokens:tt)*) => {
+        $crate::__path_const!(@build $sep [] [] $($tokens)*)
+    };
+}
+
+/// Splits `path!`-style tokens into segments and hands them to a callback.
+///
+/// `__path_segments!([callback args...] tokens...)` expands to
+/// `$crate::callback!(args... [kind ...] [kind ...] ...)`, where each bracket
+/// group describes one segment:
+///
+/// - `[root]` — a leading `/`
+/// - `[anchor Name]` — a leading `~` (`Home`) or `@config`, `@cache`, ...
+/// - `[str expr]` — a `&'static str` (identifiers, literals, dotted names)
+/// - `[string expr]` — a formatted `String` (`{%expr}`, `app_{date}.log`)
+/// - `[path expr]` — a `{expr}` value
+/// - `[opt expr]` — a `{?expr}` value
+/// - `[spread expr]` — a `{..expr}` iterator
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __path_segments {
+    // === Phase 1: Build segments (accumulate tokens until a delimiter is found) ===
+
+    // When encountering a slash `/`, complete the current segment
+    (@build $cb:tt $done:tt [$($current:tt)+] / $($rest:tt)*) => {
+        $crate::__path_segments!(@finish $cb $done [$($rest)*] $($current)+)
+    };
+
+    // When encountering a slash `/` but the current segment is empty, skip it
+    (@build $cb:tt $done:tt [] / $($rest:tt)*) => {
+        $crate::__path_segments!(@build $cb $done [] $($rest)*)
     };
+
+    // When encountering a comma `,`, complete the current segment
+    (@build $cb:tt $done:tt [$($current:tt)+] , $($rest:tt)*) => {
+        $crate::__path_segments!(@finish $cb $done [$($rest)*] $($current)+)
+    };
+
+    // When encountering a comma `,` but the current segment is empty, skip it
+    (@build $cb:tt $done:tt [] , $($rest:tt)*) => {
+        $crate::__path_segments!(@build $cb $done [] $($rest)*)
+    };
+
+    // Accumulate normal tokens (including `{expr}` groups) into the current segment
+    (@build $cb:tt $done:tt [$($current:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__path_segments!(@build $cb $done [$($current)* $next] $($rest)*)
+    };
+
+    // End of tokens: finalize the last segment (if any)
+    (@build $cb:tt $done:tt [$($current:tt)+]) => {
+        $crate::__path_segments!(@finish $cb $done [] $($current)+)
+    };
+
+    // End of tokens: invoke the callback with every segment
+    (@build [$cb:ident $($args:tt)*] [$($done:tt)*] []) => {
+        $crate::$cb!($($args)* $($done)*)
+    };
+
+    // === Helper: finalize one segment, then continue with the remaining tokens ===
+    (@next $cb:tt $done:tt [$($rest:tt)*]) => {
+        $crate::__path_segments!(@build $cb $done [] $($rest)*)
+    };
+
+    // A lone literal is a complete segment; numbers keep their spelling (`01`, not `1`)
+    (@finish $cb:tt [$($done:tt)*] $rest:tt $lit:literal) => {
+        $crate::__path_segments!(@next $cb [$($done)* [str $crate::__private::literal_segment!($lit)]] $rest)
+    };
+
+    // A lone display expression {%expr} is formatted into a complete segment
+    (@finish $cb:tt $done:tt $rest:tt { % $($expr:tt)+ }) => {
+        $crate::__path_segments!(@spec $cb $done $rest display { % $($expr)+ } $($expr)+)
+    };
+
+    // A lone optional expression {?expr} is a complete segment when `Some`, and skipped when `None`
+    (@finish $cb:tt [$($done:tt)*] $rest:tt { ? $($expr:tt)+ }) => {
+        $crate::__path_segments!(@next $cb [$($done)* [opt $($expr)+]] $rest)
+    };
+
+    // A lone spread expression {..expr} contributes one segment per item
+    (@finish $cb:tt [$($done:tt)*] $rest:tt { .. $($expr:tt)+ }) => {
+        $crate::__path_segments!(@next $cb [$($done)* [spread $($expr)+]] $rest)
+    };
+
+    // A lone variable expression {expr} is a complete segment
+    (@finish $cb:tt $done:tt $rest:tt { $($expr:tt)+ }) => {
+        $crate::__path_segments!(@spec $cb $done $rest path { $($expr)+ } $($expr)+)
+    };
+
+    // === Helper: a lone `{expr:spec}` or `{%expr:spec}` is formatted like `run_{id:04}` ===
+    (@spec $cb:tt $done:tt $rest:tt $kind:ident $group:tt : $($tokens:tt)*) => {
+        $crate::__path_segments!(@format $cb $done $rest [] [] $group)
+    };
+
+    (@spec $cb:tt $done:tt $rest:tt $kind:ident $group:tt $next:tt $($tokens:tt)*) => {
+        $crate::__path_segments!(@spec $cb $done $rest $kind $group $($tokens)*)
+    };
+
+    (@spec $cb:tt [$($done:tt)*] $rest:tt display { % $($expr:tt)+ }) => {
+        $crate::__path_segments!(@next $cb [$($done)* [string $crate::__private::ToString::to_string(&($($expr)+))]] $rest)
+    };
+
+    (@spec $cb:tt [$($done:tt)*] $rest:tt path { $($expr:tt)+ }) => {
+        $crate::__path_segments!(@next $cb [$($done)* [path $($expr)+]] $rest)
+    };
+
+    // Anything else is spelled out token by token, with `{expr}` groups formatted in place
+    (@finish $cb:tt $done:tt $rest:tt $($tokens:tt)+) => {
+        $crate::__path_segments!(@format $cb $done $rest [] [] $($tokens)+)
+    };
+
+    // === Helper: format a segment with interpolations like `app_{date}.log` ===
+    // Strip the optional `%` prefix, then split `{expr:spec}` at the first top-level `:`
+    (@format $cb:tt $done:tt $rest:tt $fmt:tt $args:tt { % $($inner:tt)+ } $($tokens:tt)*) => {
+        $crate::__path_segments!(@format_spec $cb $done $rest $fmt $args [] [$($inner)+] $($tokens)*)
+    };
+
+    (@format $cb:tt $done:tt $rest:tt $fmt:tt $args:tt { $($inner:tt)+ } $($tokens:tt)*) => {
+        $crate::__path_segments!(@format_spec $cb $done $rest $fmt $args [] [$($inner)+] $($tokens)*)
+    };
+
+    // Each plain token is stringified on its own so `my-app` or `2024-01-01` keep their exact spelling
+    (@format $cb:tt $done:tt $rest:tt [$($fmt:tt)*] $args:tt $next:tt $($tokens:tt)*) => {
+        $crate::__path_segments!(@format $cb $done $rest [$($fmt)* stringify!($next),] $args $($tokens)*)
+    };
+
+    // No interpolation: the segment is a `&'static str`
+    (@format $cb:tt [$($done:tt)*] $rest:tt [$($fmt:tt)*] []) => {
+        $crate::__path_segments!(@next $cb [$($done)* [str concat!($($fmt)*)]] $rest)
+    };
+
+    (@format $cb:tt [$($done:tt)*] $rest:tt [$($fmt:tt)*] [$($args:tt)+]) => {
+        $crate::__path_segments!(@next $cb [$($done)* [string $crate::__private::format!(concat!($($fmt)*), $($args)+)]] $rest)
+    };
+
+    (@format_spec $cb:tt $done:tt $rest:tt [$($fmt:tt)*] [$($args:tt)*] [$($expr:tt)+] [: $($spec:tt)*] $($tokens:tt)*) => {
+        $crate::__path_segments!(@format $cb $done $rest [$($fmt)* "{:", $(stringify!($spec),)* "}",] [$($args)* ($($expr)+),] $($tokens)*)
+    };
+
+    (@format_spec $cb:tt $done:tt $rest:tt $fmt:tt $args:tt [$($expr:tt)*] [$next:tt $($inner:tt)*] $($tokens:tt)*) => {
+        $crate::__path_segments!(@format_spec $cb $done $rest $fmt $args [$($expr)* $next] [$($inner)*] $($tokens)*)
+    };
+
+    (@format_spec $cb:tt $done:tt $rest:tt [$($fmt:tt)*] [$($args:tt)*] [$($expr:tt)+] [] $($tokens:tt)*) => {
+        $crate::__path_segments!(@format $cb $done $rest [$($fmt)* "{}",] [$($args)* ($($expr)+),] $($tokens)*)
+    };
+
+    // === Helper: resolve a leading `@name` anchor ===
+    (@anchor $cb:tt config $($tokens:tt)*) => {
+        $crate::__path_segments!(@anchor_start $cb Config config $($tokens)*)
+    };
+    (@anchor $cb:tt cache $($tokens:tt)*) => {
+        $crate::__path_segments!(@anchor_start $cb Cache cache $($tokens)*)
+    };
+    (@anchor $cb:tt data $($tokens:tt)*) => {
+        $crate::__path_segments!(@anchor_start $cb Data data $($tokens)*)
+    };
+    (@anchor $cb:tt temp $($tokens:tt)*) => {
+        $crate::__path_segments!(@anchor_start $cb Temp temp $($tokens)*)
+    };
+    (@anchor $cb:tt cwd $($tokens:tt)*) => {
+        $crate::__path_segments!(@anchor_start $cb Cwd cwd $($tokens)*)
+    };
+    (@anchor $cb:tt exe $($tokens:tt)*) => {
+        $crate::__path_segments!(@anchor_start $cb Exe exe $($tokens)*)
+    };
+    // A typo such as `@cahce` must not quietly become a relative path
+    (@anchor $cb:tt $name:ident $($tokens:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown base directory anchor `@", ::core::stringify!($name),
+            "`; expected one of `@config`, `@cache`, `@data`, `@temp`, `@cwd`, `@exe`"
+        ))
+    };
+
+    // Like `~`, an anchor must be followed by a separator or nothing
+    (@anchor_start [$($cb:tt)+] $kind:ident $name:ident) => {
+        $crate::__path_segments!(@build [$($cb)+] [[anchor $kind]] [])
+    };
+    (@anchor_start [$($cb:tt)+] $kind:ident $name:ident / $($tokens:tt)*) => {
+        $crate::__path_segments!(@build [$($cb)+] [[anchor $kind]] [] $($tokens)*)
+    };
+    (@anchor_start [$($cb:tt)+] $kind:ident $name:ident , $($tokens:tt)*) => {
+        $crate::__path_segments!(@build [$($cb)+] [[anchor $kind]] [] $($tokens)*)
+    };
+    (@anchor_start $cb:tt $kind:ident $name:ident $($tokens:tt)+) => {
+        ::core::compile_error!(::core::concat!(
+            "`@", ::core::stringify!($name), "` must be followed by `/`, `,` or nothing"
+        ))
+    };
+
+    // === Entry point ===
+    // Leading slash `/` → start from the filesystem root
+    ([$($cb:tt)+] / $($tokens:tt)*) => {
+        $crate::__path_segments!(@build [$($cb)+] [[root]] [] $($tokens)*)
+    };
+
+    // Leading tilde `~` or `@name` → start from a base directory resolved at runtime
+    ([$($cb:tt)+] ~) => {
+        $crate::__path_segments!(@build [$($cb)+] [[anchor Home]] [])
+    };
+    ([$($cb:tt)+] ~ / $($tokens:tt)*) => {
+        $crate::__path_segments!(@build [$($cb)+] [[anchor Home]] [] $($tokens)*)
+    };
+    ([$($cb:tt)+] ~ , $($tokens:tt)*) => {
+        $crate::__path_segments!(@build [$($cb)+] [[anchor Home]] [] $($tokens)*)
+    };
+    // `~user` and `~.config` would silently mean the current user's home
+    ([$($cb:tt)+] ~ $($tokens:tt)+) => {
+        ::core::compile_error!("`~` must be followed by `/`, `,` or nothing; `~user` home directories are not supported")
+    };
+    ([$($cb:tt)+] @ $name:ident $($tokens:tt)*) => {
+        $crate::__path_segments!(@anchor [$($cb)+] $name $($tokens)*)
+    };
+
+    ([$($cb:tt)+] $($tokens:tt)*) => {
+        $crate::__path_segments!(@build [$($cb)+] [] [] $($tokens)*)
+    };
+}
+
+/// Bytes needed by the static segments of a parsed path, as a constant.
+///
+/// Counts the root, every `[str ...]` segment and one separator after each;
+/// interpolated segments are sized at runtime when they are pushed.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __path_capacity {
+    (@segment [root]) => { 1 };
+    (@segment [str $($seg:tt)+]) => { $($seg)+.len() + 1 };
+    (@segment [$kind:ident $($expr:tt)*]) => { 0 };
+
+    ($($seg:tt)*) => {{
+        const CAPACITY: usize = 0 $(+ $crate::__path_capacity!(@segment $seg))*;
+        CAPACITY
+    }};
 }
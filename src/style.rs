@@ -0,0 +1,137 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Separator and root conventions used by [`path_str!`](crate::path_str).
+///
+/// Unlike `PathBuf`, which always follows the host
+/// platform, a `PathStyle` is chosen at runtime, so a build script running on
+/// Linux can produce Windows paths for the target it is compiling for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PathStyle {
+    /// `/` separators and a `/` root: `/usr/local/lib`.
+    Unix,
+    /// `\` separators with drive (`C:`) and UNC (`\\server\share`) prefixes:
+    /// `C:\Program Files\app`. `/` inside segments is rewritten to `\`.
+    Windows,
+    /// Windows prefix rules with `/` separators, as accepted by most Windows
+    /// tools and by MSYS2/MinGW shells: `C:/Program Files/app`. `\` inside
+    /// segments is rewritten to `/`.
+    WindowsSlash,
+}
+
+impl PathStyle {
+    /// The style of the platform this crate was compiled for.
+    pub const fn native() -> Self {
+        if cfg!(target_os = "windows") {
+            PathStyle::Windows
+        } else {
+            PathStyle::Unix
+        }
+    }
+
+    /// The style for a Rust `target_os` value, such as the
+    /// `CARGO_CFG_TARGET_OS` variable cargo passes to build scripts.
+    pub fn from_target_os(target_os: &str) -> Self {
+        if target_os == "windows" {
+            PathStyle::Windows
+        } else {
+            PathStyle::Unix
+        }
+    }
+
+    /// The separator placed between segments.
+    pub const fn separator(self) -> char {
+        match self {
+            PathStyle::Unix | PathStyle::WindowsSlash => '/',
+            PathStyle::Windows => '\\',
+        }
+    }
+
+    /// Whether `c` separates segments in this style.
+    pub const fn is_separator(self, c: char) -> bool {
+        match self {
+            PathStyle::Unix => c == '/',
+            PathStyle::Windows | PathStyle::WindowsSlash => c == '/' || c == '\\',
+        }
+    }
+
+    /// Length of the drive (`C:`) or UNC (`\\server\share`) prefix of `path`.
+    #[cfg(feature = "alloc")]
+    fn prefix_len(self, path: &str) -> usize {
+        if self == PathStyle::Unix {
+            return 0;
+        }
+        let bytes = path.as_bytes();
+        if bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' {
+            return 2;
+        }
+        let mut chars = path.char_indices();
+        match (chars.next(), chars.next()) {
+            (Some((_, a)), Some((_, b))) if self.is_separator(a) && self.is_separator(b) => {
+                // `\\server\share`: the prefix ends before the separator after `share`
+                let mut separators = 0;
+                for (i, c) in chars {
+                    if self.is_separator(c) {
+                        separators += 1;
+                        if separators == 2 {
+                            return i;
+                        }
+                    }
+                }
+                path.len()
+            }
+            _ => 0,
+        }
+    }
+
+    /// Appends `segment` to `path` the way `PathBuf::push` would on this style's
+    /// platform, using this style's separator.
+    ///
+    /// - an empty `segment` leaves `path` unchanged
+    /// - a `segment` with a prefix (or any rooted segment in [`PathStyle::Unix`])
+    ///   replaces `path`
+    /// - a rooted `segment` without a prefix keeps the prefix of `path`
+    /// - otherwise a separator is added unless `path` is empty, already ends
+    ///   with one, or is a bare drive such as `C:`
+    #[cfg(feature = "alloc")]
+    pub fn push(self, path: &mut String, segment: &str) {
+        if segment.is_empty() {
+            return;
+        }
+        let segment_prefix = self.prefix_len(segment);
+        let rooted = segment[segment_prefix..]
+            .chars()
+            .next()
+            .map_or(false, |c| self.is_separator(c));
+
+        if segment_prefix > 0 {
+            path.clear();
+        } else if rooted {
+            let keep = self.prefix_len(path);
+            path.truncate(keep);
+        } else {
+            let bare_drive = self != PathStyle::Unix && path.len() == 2 && self.prefix_len(path) == 2;
+            if !path.is_empty() && !bare_drive && !path.ends_with(|c| self.is_separator(c)) {
+                path.push(self.separator());
+            }
+        }
+
+        match self {
+            PathStyle::Unix => path.push_str(segment),
+            PathStyle::Windows | PathStyle::WindowsSlash => {
+                let separator = self.separator();
+                path.extend(
+                    segment
+                        .chars()
+                        .map(|c| if self.is_separator(c) { separator } else { c }),
+                );
+            }
+        }
+    }
+}
+
+impl Default for PathStyle {
+    fn default() -> Self {
+        PathStyle::native()
+    }
+}
@@ -0,0 +1,73 @@
+//! Compile-time UTF-16 encoding for [`wpath!`](crate::wpath).
+
+/// Number of UTF-16 code units needed to encode `s`.
+#[doc(hidden)]
+pub const fn utf16_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    let mut len = 0;
+    while i < bytes.len() {
+        let lead = bytes[i];
+        if lead < 0x80 {
+            i += 1;
+            len += 1;
+        } else if lead < 0xE0 {
+            i += 2;
+            len += 1;
+        } else if lead < 0xF0 {
+            i += 3;
+            len += 1;
+        } else {
+            // Outside the BMP: a surrogate pair
+            i += 4;
+            len += 2;
+        }
+    }
+    len
+}
+
+/// Encodes `s` as NUL-terminated UTF-16, rejecting NUL bytes inside `s`.
+///
+/// `N` must be `utf16_len(s) + 1`; the last code unit is left as the terminator.
+#[doc(hidden)]
+pub const fn encode_wide_path<const N: usize>(s: &str) -> [u16; N] {
+    let bytes = s.as_bytes();
+    let mut wide = [0u16; N];
+    let mut i = 0;
+    let mut w = 0;
+    while i < bytes.len() {
+        let lead = bytes[i] as u32;
+        let (c, width) = if lead < 0x80 {
+            (lead, 1)
+        } else if lead < 0xE0 {
+            ((lead & 0x1F) << 6 | (bytes[i + 1] as u32 & 0x3F), 2)
+        } else if lead < 0xF0 {
+            (
+                (lead & 0x0F) << 12 | (bytes[i + 1] as u32 & 0x3F) << 6 | (bytes[i + 2] as u32 & 0x3F),
+                3,
+            )
+        } else {
+            (
+                (lead & 0x07) << 18
+                    | (bytes[i + 1] as u32 & 0x3F) << 12
+                    | (bytes[i + 2] as u32 & 0x3F) << 6
+                    | (bytes[i + 3] as u32 & 0x3F),
+                4,
+            )
+        };
+        if c == 0 {
+            panic!("wpath! segments must not contain NUL bytes");
+        }
+        if c < 0x1_0000 {
+            wide[w] = c as u16;
+            w += 1;
+        } else {
+            let c = c - 0x1_0000;
+            wide[w] = 0xD800 | (c >> 10) as u16;
+            wide[w + 1] = 0xDC00 | (c & 0x3FF) as u16;
+            w += 2;
+        }
+        i += width;
+    }
+    wide
+}
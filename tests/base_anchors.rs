@@ -0,0 +1,57 @@
+use path_macro2::{path, try_path, ErrorKind};
+
+#[test]
+fn test_process_anchors() {
+    let name = "index.db";
+
+    assert_eq!(path!(@temp / myapp / {name}), std::env::temp_dir().join("myapp").join("index.db"));
+    assert_eq!(path!(@cwd / tests, fixtures), std::env::current_dir().unwrap().join("tests/fixtures"));
+
+    let exe_dir = std::env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    assert_eq!(try_path!(@exe / plugins / {name}).unwrap(), exe_dir.join("plugins").join("index.db"));
+
+    // Interpolated segments after an anchor are still checked
+    let name = "/etc/passwd";
+    let err = try_path!(@temp / myapp / {name}).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Absolute);
+    assert_eq!(err.index(), 2);
+
+    // Unknown names do not compile (see tests/ui); a quoted `@` segment is still a plain name
+    assert_eq!(path!("@myapp" / x), std::path::Path::new("@myapp").join("x"));
+}
+
+// A single test for the per-user directories, since it changes the process environment
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+#[test]
+fn test_xdg_anchors() {
+    let home = std::env::temp_dir().join("bob");
+    let xdg_cache = std::env::temp_dir().join("xdg-cache");
+    std::env::set_var("HOME", &home);
+    std::env::remove_var("XDG_CONFIG_HOME");
+    std::env::remove_var("XDG_DATA_HOME");
+    std::env::set_var("XDG_CACHE_HOME", &xdg_cache);
+
+    // === XDG variables, falling back to the home directory ===
+    assert_eq!(path!(@config / myapp / config.toml), home.join(".config/myapp/config.toml"));
+    assert_eq!(path!(@data / myapp), home.join(".local/share/myapp"));
+    assert_eq!(path!(@cache / myapp / index.db), xdg_cache.join("myapp/index.db"));
+
+    // Relative XDG paths are ignored, as the specification requires
+    std::env::set_var("XDG_CONFIG_HOME", "relative/config");
+    assert_eq!(try_path!(@config / myapp).unwrap(), home.join(".config/myapp"));
+
+    // === Unavailable ===
+    std::env::remove_var("HOME");
+    let err = try_path!(@config / myapp).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::BaseUnavailable);
+    assert_eq!(err.index(), 0);
+    assert_eq!(err.segment(), "@config");
+    assert_eq!(
+        err.to_string(),
+        "invalid path segment #0 \"@config\": base directory could not be determined"
+    );
+    assert!(std::panic::catch_unwind(|| path!(@data / myapp)).is_err());
+
+    // The cache directory does not need HOME while XDG_CACHE_HOME is set
+    assert!(try_path!(@cache / myapp).is_ok());
+}
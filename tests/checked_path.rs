@@ -0,0 +1,37 @@
+use path_macro2::{checked_path, manifest_path_const};
+use std::path::Path;
+
+#[test]
+fn test_checked_path() {
+    const LIB: &str = checked_path!(src / lib.rs);
+    const README: &str = checked_path!(file: "README.md");
+    const TESTS: &str = checked_path!(dir: tests);
+    const COMMA: &str = checked_path!(file: src, lib.rs);
+    const MANIFEST_DIR: &str = checked_path!();
+
+    assert_eq!(LIB, manifest_path_const!(src / lib.rs));
+    assert!(Path::new(README).is_file());
+    assert!(Path::new(TESTS).is_dir());
+    assert_eq!(COMMA, LIB);
+    assert_eq!(MANIFEST_DIR, env!("CARGO_MANIFEST_DIR"));
+
+    // Expands to a literal, so it composes with `concat!`
+    const INCLUDE_FLAG: &str = concat!("-I", checked_path!(dir: src));
+    assert_eq!(&INCLUDE_FLAG[2..], manifest_path_const!(src));
+}
+
+#[test]
+fn test_checked_path_literals() {
+    const HYPHENATED: &str = checked_path!(file: tests / "checked_path.rs");
+    const RAW: &str = checked_path!(file: r"tests" / r#"checked_path.rs"#);
+    const ESCAPED: &str = checked_path!(file: "tests\u{2f}checked_path.rs");
+
+    assert_eq!(HYPHENATED, RAW);
+    assert!(Path::new(ESCAPED).is_file());
+}
+
+#[test]
+fn test_checked_path_errors() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/checked_path_*.rs");
+}
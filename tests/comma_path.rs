@@ -63,3 +63,52 @@ fn test_windows_absolute_slash() {
     let unc = path!("\\\\server", "share dir", "file.txt");
     assert_eq!(unc.to_string_lossy(), "\\\\server\\share dir\\file.txt");
 }
+
+#[test]
+fn test_comma_with_path_and_display_values() {
+    let base = std::path::PathBuf::from("root");
+    let id = 7;
+    let p = path!({ base }, { %id }, file.txt);
+    assert_eq!(p, std::path::Path::new("root").join("7").join("file.txt"));
+}
+
+#[test]
+fn test_comma_leading_root() {
+    let a = path!(/ usr, local, bin);
+    let b = path!(/, usr, local, bin);
+    assert_eq!(a, b);
+    assert!(a.has_root());
+
+    #[cfg(not(target_os = "windows"))]
+    assert_eq!(a, std::path::PathBuf::from("/usr/local/bin"));
+}
+
+#[test]
+fn test_comma_hyphenated_and_digit_leading() {
+    let p = path!(my-app, 2024-01-01, v1.2.3, log.txt);
+    assert_eq!(p, path!(my-app / 2024-01-01 / v1.2.3 / log.txt));
+    assert!(p.ends_with("my-app/2024-01-01/v1.2.3/log.txt"));
+}
+
+#[test]
+fn test_comma_intra_segment_interpolation() {
+    let date = "2026-10-16";
+    let id = 42;
+    let p = path!(logs, app-{date}.log, {id:#x}.bin);
+    assert_eq!(p, std::path::Path::new("logs").join("app-2026-10-16.log").join("0x2a.bin"));
+}
+
+#[test]
+fn test_comma_optional_segments() {
+    let profile = Some(String::from("release"));
+    let none: Option<String> = None;
+    let p = path!(target, { ?none }, { ?profile }, app);
+    assert_eq!(p, std::path::Path::new("target").join("release").join("app"));
+}
+
+#[test]
+fn test_comma_spread_segments() {
+    let components = ["plugins", "enabled"];
+    let p = path!(root, { ..components }, file.toml);
+    assert_eq!(p, std::path::Path::new("root/plugins/enabled/file.toml"));
+}
@@ -107,3 +107,53 @@ fn test_comma_path_const() {
 
     println!("\n✓ All comma-separated tests passed!");
 }
+
+#[test]
+fn test_comma_path_const_leading_root() {
+    const BIN_DIR: &str = path_const!(/ usr, local, bin);
+    const WITH_COMMA: &str = path_const!(/, usr, local, bin);
+    assert_eq!(BIN_DIR, WITH_COMMA);
+
+    #[cfg(target_os = "windows")]
+    assert_eq!(BIN_DIR, "\\usr\\local\\bin");
+
+    #[cfg(not(target_os = "windows"))]
+    assert_eq!(BIN_DIR, "/usr/local/bin");
+}
+
+#[test]
+fn test_comma_path_const_root_literals() {
+    const UNIX_ROOT: &str = path_const!("/", usr, bin);
+    const DRIVE_ROOT: &str = path_const!("D:\\", data, "file name.txt");
+
+    #[cfg(target_os = "windows")]
+    {
+        assert_eq!(UNIX_ROOT, "/usr\\bin");
+        assert_eq!(DRIVE_ROOT, "D:\\data\\file name.txt");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        assert_eq!(UNIX_ROOT, "/usr/bin");
+        assert_eq!(DRIVE_ROOT, "D:\\data/file name.txt");
+    }
+}
+
+#[test]
+fn test_comma_path_const_hyphenated_and_digit_leading() {
+    const LOG: &str = path_const!(my-app, 2024-01-01, v1.2.3, log.txt);
+    assert_eq!(LOG, path_const!(my-app / 2024-01-01 / v1.2.3 / log.txt));
+
+    #[cfg(not(target_os = "windows"))]
+    assert_eq!(LOG, "my-app/2024-01-01/v1.2.3/log.txt");
+}
+
+#[test]
+fn test_comma_path_const_braced_segments() {
+    const DLL: &str = "dll";
+    const FROM_ENV: &str = path_const!({env!("CARGO_PKG_NAME")}, include);
+    const FROM_CONST: &str = path_const!(vendor, {DLL}, windivert.c);
+
+    assert_eq!(FROM_ENV, path_const!(path_macro2 / include));
+    assert_eq!(FROM_CONST, path_const!(vendor / dll / windivert.c));
+}
@@ -0,0 +1,7 @@
+#[test]
+fn test_compile_errors() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/anchor_*.rs");
+    t.compile_fail("tests/ui/manifest_path_*.rs");
+    t.compile_fail("tests/ui/path_*.rs");
+}
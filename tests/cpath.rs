@@ -0,0 +1,63 @@
+use path_macro2::{cpath, cstring_path, path_const};
+use std::ffi::CStr;
+
+#[test]
+fn test_cpath() {
+    const LIB_PATH: &CStr = cpath!(vendor / dll / windivert.c);
+    const COMMA_PATH: &CStr = cpath!(config, app.toml);
+    const ROOTED: &CStr = cpath!(/ usr / local / lib / libfoo.so.1);
+    static TABLE: &[&CStr] = &[cpath!(src / main.rs), cpath!(my-app / 2024-01-01)];
+
+    assert_eq!(LIB_PATH.to_str().unwrap(), path_const!(vendor / dll / windivert.c));
+    assert_eq!(COMMA_PATH.to_bytes_with_nul().last(), Some(&0));
+    assert_eq!(TABLE.len(), 2);
+
+    #[cfg(target_os = "windows")]
+    {
+        assert_eq!(ROOTED.to_bytes(), b"\\usr\\local\\lib\\libfoo.so.1");
+        assert_eq!(TABLE[1].to_bytes(), b"my-app\\2024-01-01");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        assert_eq!(ROOTED.to_bytes(), b"/usr/local/lib/libfoo.so.1");
+        assert_eq!(TABLE[1].to_bytes(), b"my-app/2024-01-01");
+    }
+}
+
+#[test]
+fn test_cpath_const_items() {
+    const VENDOR: &str = "vendor";
+    const SOURCE: &CStr = cpath!({VENDOR} / dll / windivert.c);
+
+    assert_eq!(SOURCE.to_str().unwrap(), path_const!({VENDOR} / dll / windivert.c));
+    assert_eq!(SOURCE.to_bytes_with_nul().last(), Some(&0));
+}
+
+#[test]
+fn test_cstring_path() {
+    let base = std::path::PathBuf::from("data");
+    let id = 7;
+    let path = cstring_path!({base} / run_{id:03} / "out file.csv").unwrap();
+
+    #[cfg(target_os = "windows")]
+    assert_eq!(path.as_bytes(), b"data\\run_007\\out file.csv");
+
+    #[cfg(not(target_os = "windows"))]
+    assert_eq!(path.as_bytes(), b"data/run_007/out file.csv");
+
+    let name = "evil\0name";
+    let err = cstring_path!(data / {name}).unwrap_err();
+    assert_eq!(err.nul_position(), "data/evil".len());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_cstring_path_non_utf8() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let name = OsStr::from_bytes(b"caf\xe9");
+    let path = cstring_path!(/ tmp / {name}).unwrap();
+    assert_eq!(path.as_bytes(), b"/tmp/caf\xe9");
+}
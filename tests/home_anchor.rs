@@ -0,0 +1,40 @@
+use path_macro2::{path, try_path, ErrorKind};
+
+#[cfg(target_os = "windows")]
+const HOME_VAR: &str = "USERPROFILE";
+#[cfg(not(target_os = "windows"))]
+const HOME_VAR: &str = "HOME";
+
+// A single test, since it changes the process environment
+#[test]
+fn test_home_anchor() {
+    let home = std::env::temp_dir().join("alice");
+    std::env::set_var(HOME_VAR, &home);
+
+    // === Resolves at runtime ===
+    let app = "my-app";
+    assert_eq!(path!(~ / .config / {app} / config.toml), home.join(".config/my-app/config.toml"));
+    assert_eq!(path!(~, .cache, {app}), home.join(".cache").join("my-app"));
+    assert_eq!(path!(~), home);
+
+    let checked = try_path!(~ / .config / {app}).unwrap();
+    assert_eq!(checked, home.join(".config").join("my-app"));
+    assert_eq!(try_path!(~).unwrap(), home);
+
+    // Segments after `~` are still checked
+    let app = "../../etc";
+    let err = try_path!(~ / .config / {app}).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ParentDir);
+    assert_eq!(err.index(), 2);
+
+    // === No home directory ===
+    std::env::set_var(HOME_VAR, "");
+    let err = try_path!(~ / .config / {app}).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::BaseUnavailable);
+    assert_eq!(err.index(), 0);
+    assert_eq!(err.segment(), "~");
+
+    std::env::remove_var(HOME_VAR);
+    assert!(std::panic::catch_unwind(|| path!(~ / .config)).is_err());
+    assert_eq!(try_path!(~).unwrap_err().kind(), ErrorKind::BaseUnavailable);
+}
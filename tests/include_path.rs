@@ -0,0 +1,21 @@
+use path_macro2::{include_path_bytes, include_path_str, manifest_path};
+
+#[test]
+fn test_include_path_str() {
+    const LIB: &str = include_path_str!(src / lib.rs);
+    const COMMA: &str = include_path_str!(src, "lib.rs");
+    const FROM_ENV: &str = include_path_str!({env!("CARGO_PKG_README")});
+
+    assert_eq!(LIB, std::fs::read_to_string(manifest_path!(src / lib.rs)).unwrap());
+    assert_eq!(COMMA, LIB);
+    assert!(FROM_ENV.starts_with("# path_macro2"));
+}
+
+#[test]
+fn test_include_path_bytes() {
+    static LICENSE: &[u8] = include_path_bytes!(LICENSE-APACHE);
+    static FIXTURE: &[u8] = include_path_bytes!(tests / ui / checked_path_missing.rs);
+
+    assert_eq!(LICENSE, &std::fs::read(manifest_path!(LICENSE-APACHE)).unwrap()[..]);
+    assert!(FIXTURE.starts_with(b"use path_macro2::checked_path;"));
+}
@@ -0,0 +1,48 @@
+use path_macro2::{manifest_path, manifest_path_const, path};
+use std::path::Path;
+
+#[test]
+fn test_manifest_path() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let test_name = "manifest_path";
+
+    let this_file = manifest_path!(tests / {test_name}.rs);
+    assert_eq!(this_file, manifest_dir.join("tests").join("manifest_path.rs"));
+    assert!(this_file.is_file());
+
+    let comma = manifest_path!(src, lib.rs);
+    assert!(comma.is_file());
+
+    let profile: Option<&str> = None;
+    assert_eq!(manifest_path!({?profile}), manifest_dir);
+    assert_eq!(manifest_path!(), manifest_dir);
+    assert_eq!(manifest_path!(benches / path.rs), path!({manifest_dir} / benches / path.rs));
+}
+
+#[test]
+fn test_manifest_path_output_types() {
+    use std::sync::Arc;
+
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let lib: String = manifest_path!(as String: src / lib.rs);
+    let shared: Arc<Path> = manifest_path!(as Arc<Path>: src / lib.rs);
+
+    assert_eq!(Path::new(&lib), manifest_dir.join("src").join("lib.rs"));
+    assert_eq!(&*shared, manifest_dir.join("src").join("lib.rs"));
+}
+
+#[test]
+fn test_manifest_path_const() {
+    const LIB: &str = manifest_path_const!(src / lib.rs);
+    const README: &str = manifest_path_const!("README.md");
+    const DIR: &str = manifest_path_const!();
+
+    assert!(Path::new(LIB).is_file());
+    assert!(Path::new(README).is_file());
+    assert_eq!(Path::new(LIB), Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join("lib.rs"));
+    assert_eq!(DIR, env!("CARGO_MANIFEST_DIR"));
+
+    // Still a literal, so it composes with `concat!`
+    const FLAG: &str = concat!("-I", manifest_path_const!(src));
+    assert!(FLAG.starts_with("-I"));
+}
@@ -0,0 +1,44 @@
+//! Builds the `no_std` surface from a `no_std` crate, so the macros must not
+//! rely on the `std` prelude (`String`, `format!`, `ToString`).
+#![no_std]
+
+extern crate alloc;
+
+use path_macro2::{path_const_unix, path_const_windows, PathStyle};
+
+#[test]
+fn test_no_std_path_const() {
+    const FIRMWARE: &str = path_const_unix!(/ sd / firmware / v1.2.3 / app.bin);
+    const CONFIG: &str = path_const_windows!(config, app.toml);
+
+    assert_eq!(FIRMWARE, "/sd/firmware/v1.2.3/app.bin");
+    assert_eq!(CONFIG, "config\\app.toml");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_no_std_path_str() {
+    use alloc::string::String;
+    use alloc::vec;
+    use path_macro2::path_str;
+
+    let volume = String::from("D:");
+    let id = 7;
+    let dirs = vec!["logs", "boot"];
+    let slot: Option<&str> = None;
+
+    assert_eq!(
+        path_str!(PathStyle::Windows; {volume} / {..&dirs} / {?slot} / run_{id:03}.log),
+        "D:logs\\boot\\run_007.log"
+    );
+    assert_eq!(
+        path_str!(PathStyle::Unix; / mnt / sd / {%id}),
+        "/mnt/sd/7"
+    );
+}
+
+#[test]
+fn test_no_std_path_style() {
+    assert_eq!(PathStyle::Windows.separator(), '\\');
+    assert!(PathStyle::Unix.is_separator('/'));
+}
@@ -0,0 +1,55 @@
+use path_macro2::path;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[test]
+fn test_path_output_types() {
+    let name = "windivert.c";
+    let expected = path!(vendor / dll / windivert.c);
+
+    let path_buf: PathBuf = path!(as PathBuf: vendor / dll / {name});
+    let os_string: OsString = path!(as OsString: vendor / dll / {name});
+    let boxed: Box<Path> = path!(as Box<Path>: vendor, dll, {name});
+    let rc: Rc<Path> = path!(as Rc<Path>: vendor / dll / {name});
+    let arc: Arc<Path> = path!(as Arc<Path>: vendor / dll / {name});
+
+    assert_eq!(path_buf, expected);
+    assert_eq!(os_string, expected.as_os_str());
+    assert_eq!(&*boxed, expected.as_path());
+    assert_eq!(&*rc, expected.as_path());
+    assert_eq!(&*arc, expected.as_path());
+}
+
+#[test]
+fn test_path_output_string() {
+    let date = "2026-10-16";
+    let profile: Option<&str> = Some("release");
+    let log: String = path!(as String: / var / log / {?profile} / app-{date}.log);
+    let comma: String = path!(as String: target, {?profile}, my-app);
+
+    assert_eq!(Path::new(&log), path!(/ var / log / release / app-2026-10-16.log));
+
+    #[cfg(target_os = "windows")]
+    {
+        assert_eq!(log, "\\var\\log\\release\\app-2026-10-16.log");
+        assert_eq!(comma, "target\\release\\my-app");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        assert_eq!(log, "/var/log/release/app-2026-10-16.log");
+        assert_eq!(comma, "target/release/my-app");
+    }
+}
+
+#[test]
+fn test_path_output_string_anchors() {
+    let name = "index.db";
+    let temp: String = path!(as String: @temp / myapp / {name});
+    let cwd: String = path!(as String: @cwd);
+
+    assert_eq!(Path::new(&temp), std::env::temp_dir().join("myapp").join(name));
+    assert_eq!(Path::new(&cwd), std::env::current_dir().unwrap());
+}
@@ -0,0 +1,89 @@
+use path_macro2::{path_str, PathStyle};
+
+#[test]
+fn test_path_str_styles() {
+    let config = "release";
+
+    assert_eq!(
+        path_str!(PathStyle::Windows; target / {config} / app.exe),
+        "target\\release\\app.exe"
+    );
+    assert_eq!(
+        path_str!(PathStyle::Unix; target, {config}, app),
+        "target/release/app"
+    );
+    assert_eq!(
+        path_str!(PathStyle::WindowsSlash; target / {config} / app.exe),
+        "target/release/app.exe"
+    );
+}
+
+#[test]
+fn test_path_str_roots() {
+    assert_eq!(path_str!(PathStyle::Unix; / usr / lib), "/usr/lib");
+    assert_eq!(path_str!(PathStyle::Windows; / Windows / System32), "\\Windows\\System32");
+    assert_eq!(
+        path_str!(PathStyle::Windows; "C:\\" / "Program Files" / app),
+        "C:\\Program Files\\app"
+    );
+    assert_eq!(path_str!(PathStyle::Windows; "C:" / app), "C:app");
+    assert_eq!(
+        path_str!(PathStyle::Windows; "\\\\server\\share" / docs),
+        "\\\\server\\share\\docs"
+    );
+    assert_eq!(
+        path_str!(PathStyle::WindowsSlash; "D:\\" / data / {"sub\\dir"}),
+        "D:/data/sub/dir"
+    );
+}
+
+#[test]
+fn test_path_str_interpolation_forms() {
+    let id = 7;
+    let profile: Option<&str> = None;
+    let parts = vec![String::from("a"), String::from("b")];
+    let host = std::path::PathBuf::from("host_dir");
+
+    assert_eq!(
+        path_str!(PathStyle::Unix; {%host.display()} / {?profile} / {..&parts} / run_{id:03}.log),
+        "host_dir/a/b/run_007.log"
+    );
+}
+
+#[test]
+fn test_path_style_push() {
+    let mut unix = String::from("/usr/lib");
+    PathStyle::Unix.push(&mut unix, "/etc");
+    assert_eq!(unix, "/etc");
+    PathStyle::Unix.push(&mut unix, "a\\b");
+    assert_eq!(unix, "/etc/a\\b");
+    PathStyle::Unix.push(&mut unix, "");
+    assert_eq!(unix, "/etc/a\\b");
+
+    let mut win = String::from("C:\\Users\\me");
+    PathStyle::Windows.push(&mut win, "\\Temp");
+    assert_eq!(win, "C:\\Temp");
+    PathStyle::Windows.push(&mut win, "a/b");
+    assert_eq!(win, "C:\\Temp\\a\\b");
+    PathStyle::Windows.push(&mut win, "D:\\data");
+    assert_eq!(win, "D:\\data");
+    PathStyle::Windows.push(&mut win, "\\\\srv\\share\\x");
+    assert_eq!(win, "\\\\srv\\share\\x");
+    PathStyle::Windows.push(&mut win, "\\y");
+    assert_eq!(win, "\\\\srv\\share\\y");
+}
+
+#[test]
+fn test_path_style_selection() {
+    assert_eq!(PathStyle::from_target_os("windows"), PathStyle::Windows);
+    assert_eq!(PathStyle::from_target_os("linux"), PathStyle::Unix);
+    assert_eq!(PathStyle::Windows.separator(), '\\');
+    assert!(PathStyle::WindowsSlash.is_separator('\\'));
+    assert!(!PathStyle::Unix.is_separator('\\'));
+
+    #[cfg(target_os = "windows")]
+    assert_eq!(PathStyle::native(), PathStyle::Windows);
+
+    #[cfg(not(target_os = "windows"))]
+    assert_eq!(PathStyle::native(), PathStyle::Unix);
+}
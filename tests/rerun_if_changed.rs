@@ -0,0 +1,25 @@
+use path_macro2::{path, path_const, rerun_if_changed};
+
+#[test]
+fn test_rerun_if_changed_macro() {
+    let [source, include_dir] = rerun_if_changed!(vendor / dll / windivert.c, vendor / include);
+    assert_eq!(source, path_const!(vendor / dll / windivert.c));
+    assert_eq!(include_dir, path_const!(vendor / include));
+
+    let single = rerun_if_changed!(build.rs);
+    assert_eq!(single, ["build.rs"]);
+
+    let trailing = rerun_if_changed!(/ usr / include / zlib.h, "my dir" / my-lib.h,);
+    assert_eq!(trailing, [path_const!(/ usr / include / zlib.h), path_const!("my dir" / my-lib.h)]);
+
+    let none: [&str; 0] = rerun_if_changed!();
+    assert!(none.is_empty());
+}
+
+#[test]
+fn test_rerun_if_changed_fn() {
+    let target_os = "linux";
+    rerun_if_changed([path!(vendor / src / {target_os}.c)]);
+    rerun_if_changed(&[path_const!(vendor / include)]);
+    rerun_if_changed(Vec::<std::path::PathBuf>::new());
+}
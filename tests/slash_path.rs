@@ -77,3 +77,168 @@ fn test_windows_absolute_slash() {
     let unc = path!("\\\\server" / "share dir" / "file.txt");
     assert_eq!(unc.to_string_lossy(), "\\\\server\\share dir\\file.txt");
 }
+
+#[test]
+fn test_slash_with_path_values() {
+    use std::ffi::OsString;
+    use std::path::{Path, PathBuf};
+
+    let base = PathBuf::from("base");
+    let sub: &Path = Path::new("sub");
+    let name = OsString::from("file.txt");
+    let p = path!({ base } / { sub } / { name });
+    assert_eq!(p, Path::new("base").join("sub").join("file.txt"));
+
+    // Values are borrowed, not consumed
+    assert_eq!(base, PathBuf::from("base"));
+}
+
+#[test]
+fn test_slash_with_display_values() {
+    let version = 2;
+    let p = path!(libs / { %version } / { %version + 1 } / library.so);
+    assert_eq!(p, std::path::Path::new("libs/2/3/library.so"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_slash_with_non_utf8_values() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let raw = OsStr::from_bytes(b"caf\xe9");
+    let p = path!(data / { raw } / file.txt);
+    assert_eq!(p.as_os_str().as_bytes(), b"data/caf\xe9/file.txt");
+}
+
+#[test]
+fn test_slash_leading_root() {
+    let p = path!(/ usr / local / bin);
+    let root = path!(/);
+
+    #[cfg(target_os = "windows")]
+    {
+        assert_eq!(p.to_string_lossy(), "\\usr\\local\\bin");
+        assert_eq!(root.to_string_lossy(), "\\");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        assert_eq!(p, std::path::PathBuf::from("/usr/local/bin"));
+        assert_eq!(root, std::path::PathBuf::from("/"));
+    }
+    assert!(p.has_root());
+}
+
+#[test]
+fn test_slash_hyphenated_and_digit_leading() {
+    let p = path!(my-app / 2024-01-01 / v1.2.3 / log.txt);
+    let q = path!(logs / 2024 / "007" / build-42.tar.gz);
+
+    #[cfg(target_os = "windows")]
+    {
+        assert_eq!(p.to_string_lossy(), "my-app\\2024-01-01\\v1.2.3\\log.txt");
+        assert_eq!(q.to_string_lossy(), "logs\\2024\\007\\build-42.tar.gz");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        assert_eq!(p.to_string_lossy(), "my-app/2024-01-01/v1.2.3/log.txt");
+        assert_eq!(q.to_string_lossy(), "logs/2024/007/build-42.tar.gz");
+    }
+}
+
+#[test]
+fn test_slash_intra_segment_interpolation() {
+    use std::path::Path;
+
+    let date = "2026-10-16";
+    let id = 7;
+    let p = path!(logs / app-{date}.log);
+    let q = path!(runs / run_{id:04} / {id}x{ id + 1 }.csv);
+    let r = path!(reports / report_{%date}_{id:>3}.csv);
+
+    assert_eq!(p, Path::new("logs").join("app-2026-10-16.log"));
+    assert_eq!(q, Path::new("runs").join("run_0007").join("7x8.csv"));
+    assert_eq!(r, Path::new("reports").join("report_2026-10-16_  7.csv"));
+
+    // A lone group with a format spec is one formatted segment
+    let s = path!(runs / {id:04} / {%id:>3} / {std::string::String::from("x")});
+    assert_eq!(s, Path::new("runs").join("0007").join("  7").join("x"));
+}
+
+#[test]
+fn test_slash_optional_segments() {
+    use std::path::{Path, PathBuf};
+
+    let profile: Option<&str> = Some("dev");
+    let tenant: Option<PathBuf> = None;
+    let p = path!(config / { ?tenant } / { ?profile } / app.toml);
+    assert_eq!(p, Path::new("config").join("dev").join("app.toml"));
+
+    let q = path!({ ?tenant } / app.toml);
+    assert_eq!(q, Path::new("app.toml"));
+
+    // The option is borrowed, not consumed
+    assert_eq!(profile, Some("dev"));
+}
+
+#[test]
+fn test_slash_spread_segments() {
+    use std::path::{Path, PathBuf};
+
+    let components = vec![String::from("a"), String::from("b")];
+    let p = path!(root / { ..&components } / file.toml);
+    assert_eq!(p, Path::new("root/a/b/file.toml"));
+
+    let empty: Vec<PathBuf> = Vec::new();
+    let q = path!(root / { ..empty } / file.toml);
+    assert_eq!(q, Path::new("root/file.toml"));
+
+    let r = path!({ ..components.iter().rev() } / { ..["x", "", "y"] });
+    assert_eq!(r, Path::new("b/a/x/y"));
+}
+
+#[test]
+fn test_slash_fully_qualified() {
+    let p = path_macro2::path!(vendor / {"dll"} / {..["x"]});
+    assert_eq!(p, std::path::Path::new("vendor/dll/x"));
+}
+
+#[test]
+fn test_slash_reserves_static_capacity() {
+    // Static segments are sized up front, so building them never reallocates
+    let p = path!(/ vendor / dll / windivert.c);
+    assert!(p.capacity() >= "/vendor/dll/windivert.c".len());
+
+    let name = "a-much-longer-interpolated-file-name.txt";
+    let q = path!(vendor / {name});
+    assert_eq!(q, std::path::Path::new("vendor").join(name));
+}
+
+#[test]
+fn test_slash_numeric_literal_segments() {
+    let date = path!(logs / 2024 / 01 / 05);
+    let hex = path!(v / 0x1F);
+    let comma = path!(logs, 2024, 007);
+
+    #[cfg(target_os = "windows")]
+    {
+        assert_eq!(date.to_string_lossy(), "logs\\2024\\01\\05");
+        assert_eq!(hex.to_string_lossy(), "v\\0x1F");
+        assert_eq!(comma.to_string_lossy(), "logs\\2024\\007");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        assert_eq!(date.to_string_lossy(), "logs/2024/01/05");
+        assert_eq!(hex.to_string_lossy(), "v/0x1F");
+        assert_eq!(comma.to_string_lossy(), "logs/2024/007");
+    }
+}
+
+#[test]
+fn test_slash_other_literal_segments() {
+    let p = path!(a / 'x' / -1 / true / '\u{e9}');
+    assert_eq!(p, std::path::Path::new("a").join("x").join("-1").join("true").join("é"));
+}
@@ -93,3 +93,142 @@ fn test_slash_path_const() {
 
     println!("\n✓ All slash-separated tests passed!");
 }
+
+#[test]
+fn test_slash_path_const_leading_root() {
+    const BIN_DIR: &str = path_const!(/ usr / local / bin);
+    const ROOT: &str = path_const!(/);
+
+    #[cfg(target_os = "windows")]
+    {
+        assert_eq!(BIN_DIR, "\\usr\\local\\bin");
+        assert_eq!(ROOT, "\\");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        assert_eq!(BIN_DIR, "/usr/local/bin");
+        assert_eq!(ROOT, "/");
+    }
+}
+
+#[test]
+fn test_slash_path_const_root_literals() {
+    // Root segments are not followed by a second separator
+    const UNIX_ROOT: &str = path_const!("/" / usr / bin);
+    const DRIVE_ROOT: &str = path_const!("C:\\" / "Program Files" / app.exe);
+    const DRIVE_ROOT_FWD: &str = path_const!("C:/" / tools);
+    const UNC: &str = path_const!("\\\\" / server / share);
+
+    #[cfg(target_os = "windows")]
+    {
+        assert_eq!(UNIX_ROOT, "/usr\\bin");
+        assert_eq!(DRIVE_ROOT, "C:\\Program Files\\app.exe");
+        assert_eq!(DRIVE_ROOT_FWD, "C:/tools");
+        assert_eq!(UNC, "\\\\server\\share");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        assert_eq!(UNIX_ROOT, "/usr/bin");
+        assert_eq!(DRIVE_ROOT, "C:\\Program Files/app.exe");
+        assert_eq!(DRIVE_ROOT_FWD, "C:/tools");
+        assert_eq!(UNC, "\\\\server/share");
+    }
+}
+
+#[test]
+fn test_slash_path_const_hyphenated_and_digit_leading() {
+    const LOG: &str = path_const!(my-app / 2024-01-01 / v1.2.3 / log.txt);
+    const CRATE_DIR: &str = path_const!(crates / path-macro2-core / 0.1.3);
+
+    #[cfg(target_os = "windows")]
+    {
+        assert_eq!(LOG, "my-app\\2024-01-01\\v1.2.3\\log.txt");
+        assert_eq!(CRATE_DIR, "crates\\path-macro2-core\\0.1.3");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        assert_eq!(LOG, "my-app/2024-01-01/v1.2.3/log.txt");
+        assert_eq!(CRATE_DIR, "crates/path-macro2-core/0.1.3");
+    }
+}
+
+#[test]
+fn test_slash_path_const_macro_segments() {
+    const VENDOR_INCLUDE: &str = path_const!({env!("CARGO_MANIFEST_DIR")} / vendor / include);
+    const NESTED: &str = path_const!({concat!("win", "divert")} / {path_const!(dll / x64)} / sys);
+    const FLAG: &str = concat!("/I", path_const!({env!("CARGO_PKG_NAME")} / include));
+
+    assert_eq!(
+        std::path::Path::new(VENDOR_INCLUDE),
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("vendor").join("include")
+    );
+
+    #[cfg(target_os = "windows")]
+    {
+        assert_eq!(NESTED, "windivert\\dll\\x64\\sys");
+        assert_eq!(FLAG, "/Ipath_macro2\\include");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        assert_eq!(NESTED, "windivert/dll/x64/sys");
+        assert_eq!(FLAG, "/Ipath_macro2/include");
+    }
+}
+
+mod dirs {
+    pub const VENDOR: &str = "vendor";
+}
+
+#[test]
+fn test_slash_path_const_const_items() {
+    const DLL: &str = "dll";
+    const ROOT: &str = path_const!(/ opt);
+    const SOURCE: &str = path_const!({dirs::VENDOR} / {DLL} / windivert.c);
+    const ROOTED: &str = path_const!({ROOT} / my-app / {env!("CARGO_PKG_NAME")});
+    const ANCHORED: &str = path_const!(/ {DLL} / "file name.txt");
+    static TABLE: &[&str] = &[path_const!({DLL}), path_const!({DLL} / {DLL})];
+
+    #[cfg(target_os = "windows")]
+    {
+        assert_eq!(SOURCE, "vendor\\dll\\windivert.c");
+        assert_eq!(ROOTED, "\\opt\\my-app\\path_macro2");
+        assert_eq!(ANCHORED, "\\dll\\file name.txt");
+        assert_eq!(TABLE, ["dll", "dll\\dll"]);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        assert_eq!(SOURCE, "vendor/dll/windivert.c");
+        assert_eq!(ROOTED, "/opt/my-app/path_macro2");
+        assert_eq!(ANCHORED, "/dll/file name.txt");
+        assert_eq!(TABLE, ["dll", "dll/dll"]);
+    }
+}
+
+#[test]
+fn test_slash_path_const_numeric_literal_segments() {
+    const DATE: &str = path_const!(logs / 2024 / 01 / 05);
+    const HEX: &str = path_const!(v / 0x1F);
+    const FLAG: &str = concat!("--out=", path_const!(logs / 01));
+    const OTHER: &str = path_const!(v / -1 / 'x' / true);
+
+    #[cfg(target_os = "windows")]
+    {
+        assert_eq!(DATE, "logs\\2024\\01\\05");
+        assert_eq!(HEX, "v\\0x1F");
+        assert_eq!(FLAG, "--out=logs\\01");
+        assert_eq!(OTHER, "v\\-1\\x\\true");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        assert_eq!(DATE, "logs/2024/01/05");
+        assert_eq!(HEX, "v/0x1F");
+        assert_eq!(FLAG, "--out=logs/01");
+        assert_eq!(OTHER, "v/-1/x/true");
+    }
+}
@@ -0,0 +1,50 @@
+use path_macro2::{path, path_const, static_path, ConstPath};
+use std::path::{Path, PathBuf};
+
+static CONFIG: ConstPath = static_path!(config / app.toml);
+const TABLE: &[ConstPath] = &[
+    static_path!(vendor / dll / windivert.c),
+    static_path!(vendor, include),
+    static_path!(/ usr / local / bin),
+];
+
+#[test]
+fn test_static_path() {
+    let config: &'static Path = CONFIG.as_path();
+    assert_eq!(config, path!(config / app.toml));
+    assert_eq!(CONFIG.as_str(), path_const!(config / app.toml));
+    assert_eq!(CONFIG.extension().unwrap(), "toml");
+
+    assert_eq!(TABLE[0], path!(vendor / dll / windivert.c));
+    assert_eq!(PathBuf::from(TABLE[1]), path!(vendor / include));
+    assert!(TABLE[2].has_root());
+    assert_eq!(TABLE[2].to_string(), path_const!(/ usr / local / bin));
+}
+
+#[test]
+fn test_const_path_new() {
+    const TOOL: ConstPath = ConstPath::new(path_const!(tools / my-tool));
+    let joined = TOOL.join("bin");
+    assert_eq!(joined, path!(tools / my-tool / bin));
+    assert_eq!(format!("{:?}", TOOL), format!("{:?}", TOOL.as_path()));
+
+    fn takes_as_ref(path: impl AsRef<Path>) -> PathBuf {
+        path.as_ref().to_path_buf()
+    }
+    assert_eq!(takes_as_ref(TOOL), TOOL);
+}
+
+#[test]
+fn test_const_path_compares_as_path() {
+    use std::collections::HashSet;
+
+    let doubled = ConstPath::new("a//b");
+    let plain = ConstPath::new("a/b");
+    assert_eq!(doubled, plain);
+    assert_eq!(doubled.cmp(&plain), std::cmp::Ordering::Equal);
+    assert_eq!(doubled, *Path::new("a/b"));
+
+    let set: HashSet<ConstPath> = [doubled, plain, ConstPath::new("a/b/")].into_iter().collect();
+    assert_eq!(set.len(), 1);
+    assert!(ConstPath::new("a/b") < ConstPath::new("a/c"));
+}
@@ -0,0 +1,73 @@
+use path_macro2::{path_const, path_const_unix, path_const_windows};
+
+#[test]
+fn test_path_const_windows_style() {
+    const LIB_PATH: &str = path_const_windows!(vendor / dll / windivert.c);
+    const COMMA: &str = path_const_windows!(vendor, "include files", windivert.h);
+    const ROOTED: &str = path_const_windows!(/ Windows / System32);
+    const DRIVE: &str = path_const_windows!("C:\\" / "Program Files" / app.exe);
+    const DEF_FLAG: &str = concat!("/DEF:", path_const_windows!(vendor / dll / windivert.def));
+
+    assert_eq!(LIB_PATH, "vendor\\dll\\windivert.c");
+    assert_eq!(COMMA, "vendor\\include files\\windivert.h");
+    assert_eq!(ROOTED, "\\Windows\\System32");
+    assert_eq!(DRIVE, "C:\\Program Files\\app.exe");
+    assert_eq!(DEF_FLAG, "/DEF:vendor\\dll\\windivert.def");
+}
+
+#[test]
+fn test_path_const_unix_style() {
+    const LIB_PATH: &str = path_const_unix!(vendor / dll / windivert.c);
+    const COMMA: &str = path_const_unix!(vendor, "include files", windivert.h);
+    const ROOTED: &str = path_const_unix!(/ usr / local / lib);
+    const HYPHENATED: &str = path_const_unix!(my-app / 2024-01-01 / v1.2.3);
+
+    assert_eq!(LIB_PATH, "vendor/dll/windivert.c");
+    assert_eq!(COMMA, "vendor/include files/windivert.h");
+    assert_eq!(ROOTED, "/usr/local/lib");
+    assert_eq!(HYPHENATED, "my-app/2024-01-01/v1.2.3");
+}
+
+#[test]
+fn test_path_const_matches_native_style() {
+    #[cfg(target_os = "windows")]
+    assert_eq!(path_const!(a / b.c), path_const_windows!(a / b.c));
+
+    #[cfg(not(target_os = "windows"))]
+    assert_eq!(path_const!(a / b.c), path_const_unix!(a / b.c));
+}
+
+#[test]
+fn test_path_const_styled_trailing_separators() {
+    // Drive roots are recognized in either case
+    const LOWER_DRIVE: &str = path_const_windows!("c:\\" / foo);
+    const LOWER_DRIVE_FWD: &str = path_const_windows!("d:/" / tools);
+    const UNC: &str = path_const_windows!("\\\\" / server / share);
+
+    assert_eq!(LOWER_DRIVE, "c:\\foo");
+    assert_eq!(LOWER_DRIVE_FWD, "d:/tools");
+    assert_eq!(UNC, "\\\\server\\share");
+
+    // `{CONST}` segments skip the separator after any trailing one
+    const USR: &str = "/usr/";
+    const VENDOR: &str = "vendor\\";
+    const UNIX_JOINED: &str = path_const_unix!({USR} / bin);
+    const WINDOWS_JOINED: &str = path_const_windows!({VENDOR} / bin);
+
+    assert_eq!(UNIX_JOINED, "/usr/bin");
+    assert_eq!(WINDOWS_JOINED, "vendor\\bin");
+}
+
+#[test]
+fn test_path_const_styled_literal_trailing_separators() {
+    // Literals follow the same rule as `{CONST}` segments and `PathBuf::push`
+    const UNIX: &str = path_const_unix!("/usr/" / bin);
+    const WINDOWS: &str = path_const_windows!("vendor\\" / bin);
+    const DRIVE: &str = path_const_windows!("c:\\" / "Program Files/" / app);
+    const USR: &str = "/usr/";
+
+    assert_eq!(UNIX, "/usr/bin");
+    assert_eq!(UNIX, path_const_unix!({USR} / bin));
+    assert_eq!(WINDOWS, "vendor\\bin");
+    assert_eq!(DRIVE, "c:\\Program Files/app");
+}
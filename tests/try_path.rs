@@ -0,0 +1,157 @@
+use path_macro2::{try_path, ErrorKind};
+use std::path::Path;
+
+#[test]
+fn test_try_path_accepts_plain_segments() {
+    let base = std::path::PathBuf::from("uploads");
+    let user = "alice";
+    let id = 7;
+    let tenant: Option<&str> = None;
+    let parts = ["a", "b"];
+
+    let p = try_path!({ base } / { user } / { ?tenant } / { ..parts } / file_{id}.png).unwrap();
+    assert_eq!(p, Path::new("uploads/alice/a/b/file_7.png"));
+
+    // Static segments are trusted, including roots
+    let abs = try_path!(/ srv / "data" / { user }).unwrap();
+    assert!(abs.has_root());
+
+    // So is the base directory
+    let upload_dir = std::env::temp_dir();
+    let p = try_path!({ upload_dir } / { user }).unwrap();
+    assert_eq!(p, upload_dir.join("alice"));
+}
+
+#[test]
+fn test_try_path_rejects_escaping_segments() {
+    let base = "uploads";
+
+    let cases: &[(&str, ErrorKind)] = &[
+        ("/etc/passwd", ErrorKind::Absolute),
+        ("../../x", ErrorKind::ParentDir),
+        ("..", ErrorKind::ParentDir),
+        (".", ErrorKind::CurDir),
+        ("./x", ErrorKind::CurDir),
+        ("x.", ErrorKind::Trailing),
+        ("x ", ErrorKind::Trailing),
+        ("...", ErrorKind::Trailing),
+        ("a/b", ErrorKind::Separator),
+        ("a\0b", ErrorKind::Nul),
+    ];
+    for (user, kind) in cases {
+        let err = try_path!({ base } / { user }).unwrap_err();
+        assert_eq!(err.kind(), *kind, "{:?}", user);
+        assert_eq!(err.segment(), *user);
+        assert_eq!(err.index(), 1);
+    }
+}
+
+#[test]
+fn test_try_path_rejects_windows_prefixes() {
+    let err = try_path!(uploads / { "C:evil" }).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Prefix);
+    let err = try_path!(uploads / { "a\\b" }).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Separator);
+
+    // Rejected on every platform, not only where Windows parses them
+    let base = "uploads";
+    let cases: &[(&str, ErrorKind)] = &[
+        ("C:\\x", ErrorKind::Prefix),
+        ("C:", ErrorKind::Prefix),
+        ("c:x", ErrorKind::Prefix),
+        ("\\\\server\\share", ErrorKind::Prefix),
+        ("\\Windows", ErrorKind::Absolute),
+    ];
+    for (user, kind) in cases {
+        let err = try_path!({ base } / { user }).unwrap_err();
+        assert_eq!(err.kind(), *kind, "{:?}", user);
+        assert_eq!(err.index(), 1);
+    }
+}
+
+#[test]
+fn test_try_path_checks_every_interpolation_form() {
+    let bad = "../x";
+    assert!(try_path!(a / { %bad }).is_err());
+    assert!(try_path!(a / { ?Some(bad) }).is_err());
+    assert!(try_path!(a / { ..["ok", bad] }).is_err());
+    assert!(try_path!(a / pre{bad}).is_err());
+}
+
+#[test]
+fn test_try_path_checks_leading_interpolations() {
+    // Only a plain `{expr}` or a static segment is trusted as the base
+    let parts = ["ok", "../evil"];
+    let err = try_path!({ ..parts } / x).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ParentDir);
+    assert_eq!(err.index(), 0);
+
+    let err = try_path!({ ?Some("/etc") } / x).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Absolute);
+    assert_eq!(err.index(), 0);
+
+    let err = try_path!(user_{"/x"} / y).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Separator);
+    assert_eq!(err.index(), 0);
+
+    let p = try_path!({ ..["a", "b"] } / { ?None::<&str> } / c).unwrap();
+    assert_eq!(p, Path::new("a/b/c"));
+}
+
+#[test]
+fn test_try_path_stops_at_first_error() {
+    let mut evaluated = false;
+    let result = try_path!(base / { "/abs" } / {{
+        evaluated = true;
+        "next"
+    }});
+    assert!(result.is_err());
+    assert!(!evaluated);
+}
+
+#[test]
+fn test_try_path_error_display() {
+    let err = try_path!(a / { "../x" }).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid path segment #1 \"../x\": segment refers to a parent directory"
+    );
+}
+
+#[test]
+fn test_try_path_rejects_non_portable_segments() {
+    let long = "x".repeat(path_macro2::MAX_SEGMENT_LEN + 1);
+    let cases: &[(&str, ErrorKind)] = &[
+        ("", ErrorKind::Empty),
+        ("CON", ErrorKind::Reserved),
+        ("nul.txt", ErrorKind::Reserved),
+        ("com1", ErrorKind::Reserved),
+        ("LPT9 .log", ErrorKind::Reserved),
+        (&long, ErrorKind::TooLong),
+    ];
+    for (name, kind) in cases {
+        let err = try_path!(data / { name }).unwrap_err();
+        assert_eq!(err.kind(), *kind, "{:?}", name);
+    }
+
+    // Names that merely contain a reserved word are fine
+    assert!(try_path!(data / { "console.log" } / { "x".repeat(255) }).is_ok());
+    // `None` is not an empty value
+    assert!(try_path!(data / { ?None::<&str> }).is_ok());
+}
+
+#[test]
+fn test_try_path_reports_segment_index() {
+    let base = "base";
+    let parts = ["ok", "a/b"];
+    let name = "ok";
+
+    let err = try_path!({ base } / static_dir / { name } / { ..parts } / tail).unwrap_err();
+    assert_eq!(err.index(), 3);
+    assert_eq!(err.segment(), "a/b");
+
+    // Without a base directory, the first segment after the root is index 0
+    let err = try_path!(/ { "" }).unwrap_err();
+    assert_eq!(err.index(), 0);
+    assert_eq!(err.kind(), ErrorKind::Empty);
+}
@@ -0,0 +1,7 @@
+use path_macro2::path;
+
+fn main() {
+    let other = path!(~root / x);
+    let hidden = path!(~.config);
+    println!("{:?} {:?}", other, hidden);
+}
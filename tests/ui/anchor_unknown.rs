@@ -0,0 +1,8 @@
+use path_macro2::{path, try_path};
+
+fn main() {
+    let typo = path!(@cahce / myapp);
+    let checked = try_path!(@myapp / x);
+    let suffixed = path!(@cache.old / myapp);
+    println!("{:?} {:?} {:?}", typo, checked, suffixed);
+}
@@ -0,0 +1,6 @@
+use path_macro2::checked_path;
+
+fn main() {
+    let name = "lib.rs";
+    println!("{}", checked_path!(src / {name}));
+}
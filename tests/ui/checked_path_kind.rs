@@ -0,0 +1,8 @@
+use path_macro2::checked_path;
+
+const NOT_A_DIR: &str = checked_path!(dir: Cargo.toml);
+const NOT_A_FILE: &str = checked_path!(file: ".");
+
+fn main() {
+    println!("{} {}", NOT_A_DIR, NOT_A_FILE);
+}
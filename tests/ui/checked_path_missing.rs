@@ -0,0 +1,7 @@
+use path_macro2::checked_path;
+
+const FIXTURE: &str = checked_path!(tests / fixtures / sample.json);
+
+fn main() {
+    println!("{}", FIXTURE);
+}
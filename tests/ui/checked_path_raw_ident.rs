@@ -0,0 +1,7 @@
+use path_macro2::checked_path;
+
+const SOURCES: &str = checked_path!(r#type / lib.rs);
+
+fn main() {
+    println!("{}", SOURCES);
+}
@@ -0,0 +1,8 @@
+use path_macro2::manifest_path;
+
+fn main() {
+    let home = manifest_path!(~ / x);
+    let cache = manifest_path!(@cache / x);
+    let typo = manifest_path!(as Strng: x);
+    println!("{:?} {:?} {:?}", home, cache, typo);
+}
@@ -0,0 +1,10 @@
+use path_macro2::{include_path_str, manifest_path_const, path_const};
+
+const HOME: &str = path_const!(~ / x);
+const CACHE: &str = path_const!(@cache / x);
+const FIXTURE: &str = manifest_path_const!(@temp / x);
+const README: &str = include_path_str!(~ / README.md);
+
+fn main() {
+    println!("{} {} {} {}", HOME, CACHE, FIXTURE, README);
+}
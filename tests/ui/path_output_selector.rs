@@ -0,0 +1,7 @@
+use path_macro2::path;
+
+fn main() {
+    let typo = path!(as Strng: a / b);
+    let qualified = path!(as Box<std::path::Path>: a);
+    println!("{:?} {:?}", typo, qualified);
+}
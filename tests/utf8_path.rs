@@ -0,0 +1,50 @@
+use camino::{Utf8Path, Utf8PathBuf};
+use path_macro2::{path, utf8_path};
+
+#[test]
+fn test_utf8_path() {
+    let base = Utf8PathBuf::from("vendor");
+    let name: &Utf8Path = Utf8Path::new("windivert.c");
+    let version = 2;
+
+    let slash: Utf8PathBuf = utf8_path!({base} / dll / {name});
+    let comma: Utf8PathBuf = utf8_path!(vendor, dll, "windivert.c");
+    assert_eq!(slash, comma);
+    assert_eq!(slash.as_std_path(), path!(vendor / dll / windivert.c));
+
+    let display = utf8_path!(libs / {%version} / lib_{version:03}.so);
+    assert_eq!(display.as_std_path(), path!(libs / "2" / lib_002.so));
+
+    #[cfg(target_os = "windows")]
+    assert_eq!(slash, "vendor\\dll\\windivert.c");
+
+    #[cfg(not(target_os = "windows"))]
+    assert_eq!(slash, "vendor/dll/windivert.c");
+}
+
+#[test]
+fn test_utf8_path_root_optional_and_spread() {
+    let tenant: Option<String> = Some(String::from("acme"));
+    let profile: Option<&str> = None;
+    let components = vec![String::from("nested"), String::from("dirs")];
+
+    let p = utf8_path!(/ srv / {?tenant} / {?profile} / {..&components} / my-app / 2024-01-01);
+    assert_eq!(p.as_std_path(), path!(/ srv / acme / nested / dirs / my-app / 2024-01-01));
+    assert!(p.is_absolute() || cfg!(target_os = "windows"));
+    assert!(p.has_root());
+}
+
+#[test]
+fn test_path_as_utf8_path_buf() {
+    let krate = "my-crate";
+    let p: Utf8PathBuf = path!(as Utf8PathBuf: crates / {krate} / Cargo.toml);
+    assert_eq!(p, utf8_path!(crates / my-crate / Cargo.toml));
+}
+
+#[test]
+fn test_utf8_path_home_anchor() {
+    let home_var = if cfg!(target_os = "windows") { "USERPROFILE" } else { "HOME" };
+    if let Some(home) = std::env::var(home_var).ok().filter(|home| !home.is_empty()) {
+        assert_eq!(utf8_path!(~ / .config / app), Utf8Path::new(&home).join(".config").join("app"));
+    }
+}
@@ -0,0 +1,39 @@
+use path_macro2::{path_const_windows, wpath};
+
+fn wide(s: &str) -> Vec<u16> {
+    s.encode_utf16().chain(Some(0)).collect()
+}
+
+#[test]
+fn test_wpath() {
+    const LIB_PATH: &[u16] = wpath!(vendor / dll / windivert.c);
+    const COMMA_PATH: &[u16] = wpath!(config, app.toml);
+    const ROOTED: &[u16] = wpath!(/ Windows / System32);
+    const DRIVE: &[u16] = wpath!("C:\\" / "Program Files" / my-app / v1.2.3);
+    static TABLE: &[&[u16]] = &[wpath!(src / main.rs), wpath!(src, lib.rs)];
+
+    // Windows separators on every host
+    assert_eq!(LIB_PATH, &wide("vendor\\dll\\windivert.c")[..]);
+    assert_eq!(COMMA_PATH, &wide(path_const_windows!(config / app.toml))[..]);
+    assert_eq!(ROOTED, &wide("\\Windows\\System32")[..]);
+    assert_eq!(DRIVE, &wide("C:\\Program Files\\my-app\\v1.2.3")[..]);
+    assert_eq!(TABLE[1], &wide("src\\lib.rs")[..]);
+}
+
+#[test]
+fn test_wpath_const_items() {
+    const VENDOR: &str = "vendor";
+    const ROOT: &str = "C:\\";
+    const SOURCE: &[u16] = wpath!({VENDOR} / dll / windivert.c);
+    const DRIVER: &[u16] = wpath!({ROOT} / Windows / {VENDOR});
+
+    assert_eq!(SOURCE, &wide("vendor\\dll\\windivert.c")[..]);
+    assert_eq!(DRIVER, &wide("C:\\Windows\\vendor")[..]);
+}
+
+#[test]
+fn test_wpath_non_ascii() {
+    const NAMES: &[u16] = wpath!("données" / "日本語" / "🦀.txt");
+    assert_eq!(NAMES, &wide("données\\日本語\\🦀.txt")[..]);
+    assert_eq!(String::from_utf16(&NAMES[..NAMES.len() - 1]).unwrap(), "données\\日本語\\🦀.txt");
+}